@@ -0,0 +1,299 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::replay::ParseError;
+use crate::router::{ControlScheme, Router, RouterCommand};
+
+/// The directory below the XDG config directory that holds the configuration of the game.
+pub const CONFIG_DIR_NAME: &str = "spider";
+/// The name of the key bindings file in `CONFIG_DIR_NAME`.
+pub const BINDINGS_FILE_NAME: &str = "bindings.conf";
+
+/// The keys bound to a command, by name. The names are the ones of the frontend, for example the
+/// GDK key names such as `Up`, `space` or `a`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyBinding {
+    pub command: RouterCommand,
+    pub keys: Vec<String>,
+    /// The line of the bindings file the binding was read from, or 0 if it wasn't read from a file.
+    pub line: usize,
+}
+
+/// Key bindings that replace the default bindings of a frontend, together with the control scheme
+/// of the player.
+///
+/// The bindings are stored as plain text, one entry per line:
+///
+/// ```text
+/// scheme hold
+/// UP Up w k
+/// DOWN Down s j
+/// PAUSE p Escape
+/// ```
+///
+/// A command line lists all keys of the command; the keys a frontend binds to the command by
+/// default are dropped. A command without keys is unbound. Commands that are not listed keep their
+/// default keys. Empty lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bindings {
+    control_scheme_: Option<ControlScheme>,
+    bindings_: Vec<KeyBinding>,
+}
+
+impl Bindings {
+    pub fn new() -> Bindings {
+        Bindings::default()
+    }
+
+    /// The complete bindings of `router`: its control scheme and the keys of every command that
+    /// can be bound.
+    /// `key_name` names the keys of the frontend; keys without a name are left out.
+    pub fn from_router<KeyT, F>(router: &Router<KeyT>, key_name: F) -> Bindings
+    where
+        KeyT: Eq + std::hash::Hash,
+        F: Fn(&KeyT) -> Option<String>,
+    {
+        let mut bindings = Bindings::new();
+        bindings.set_control_scheme(Some(router.control_scheme()));
+        for command in RouterCommand::bindable() {
+            let mut keys: Vec<String> = router
+                .keys(command)
+                .into_iter()
+                .filter_map(&key_name)
+                .collect();
+            // The router doesn't keep its keys in order.
+            keys.sort();
+            bindings.set_keys(command, keys);
+        }
+        bindings
+    }
+
+    /// The control scheme to use, or `None` to keep the frontend's default.
+    pub fn control_scheme(&self) -> Option<ControlScheme> {
+        self.control_scheme_
+    }
+
+    pub fn set_control_scheme(&mut self, control_scheme: Option<ControlScheme>) {
+        self.control_scheme_ = control_scheme;
+    }
+
+    pub fn bindings(&self) -> &[KeyBinding] {
+        &self.bindings_
+    }
+
+    /// The keys bound to `command`, or `None` if the command keeps its default keys.
+    pub fn keys(&self, command: RouterCommand) -> Option<&[String]> {
+        self.bindings_
+            .iter()
+            .find(|binding| binding.command == command)
+            .map(|binding| binding.keys.as_slice())
+    }
+
+    /// The command the key named `key` is bound to, if any.
+    pub fn command(&self, key: &str) -> Option<RouterCommand> {
+        self.bindings_
+            .iter()
+            .find(|binding| binding.keys.iter().any(|bound| bound == key))
+            .map(|binding| binding.command)
+    }
+
+    /// Replaces the keys bound to `command`.
+    pub fn set_keys(&mut self, command: RouterCommand, keys: Vec<String>) {
+        match self
+            .bindings_
+            .iter_mut()
+            .find(|binding| binding.command == command)
+        {
+            Some(binding) => binding.keys = keys,
+            None => self.bindings_.push(KeyBinding {
+                command,
+                keys,
+                line: 0,
+            }),
+        }
+    }
+
+    /// Adds `key` to the keys of `command`. A key is bound to one command only, so it is removed
+    /// from the command it was bound to before, which is returned.
+    pub fn add_key(&mut self, command: RouterCommand, key: &str) -> Option<RouterCommand> {
+        let previous = self.command(key);
+        if previous == Some(command) {
+            return previous;
+        }
+        if let Some(previous) = previous {
+            let keys = self.keys(previous).unwrap_or_default();
+            let keys = keys.iter().filter(|bound| *bound != key).cloned().collect();
+            self.set_keys(previous, keys);
+        }
+
+        let mut keys = self.keys(command).unwrap_or_default().to_vec();
+        keys.push(String::from(key));
+        self.set_keys(command, keys);
+        previous
+    }
+
+    pub fn parse(text: &str) -> Result<Bindings, ParseError> {
+        let mut bindings = Bindings::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            bindings
+                .parse_entry(line, index + 1)
+                .map_err(|message| ParseError::new(index + 1, message))?;
+        }
+
+        Ok(bindings)
+    }
+
+    /// Reads the bindings from `path`. Returns `Ok(None)` if the file does not exist.
+    pub fn load(path: &Path) -> Result<Option<Bindings>, LoadError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(LoadError::Io(err)),
+        };
+
+        Bindings::parse(&text).map(Some).map_err(LoadError::Parse)
+    }
+
+    /// Writes the bindings to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Applies the bindings to a router that holds the default bindings. `key_from_name` turns key
+    /// names into the keys of the frontend. Fails without changing the router if a key name is
+    /// not known.
+    pub fn apply<KeyT, F>(
+        &self,
+        router: &mut Router<KeyT>,
+        key_from_name: F,
+    ) -> Result<(), ParseError>
+    where
+        KeyT: Eq + std::hash::Hash,
+        F: Fn(&str) -> Option<KeyT>,
+    {
+        let mut resolved = Vec::new();
+        for binding in &self.bindings_ {
+            let mut keys = Vec::new();
+            for name in &binding.keys {
+                let key = key_from_name(name).ok_or_else(|| {
+                    ParseError::new(binding.line, format!("unknown key '{}'", name))
+                })?;
+                keys.push(key);
+            }
+            resolved.push((binding.command, keys));
+        }
+
+        for (command, _) in &resolved {
+            router.unbind_command(*command);
+        }
+        for (command, keys) in resolved {
+            for key in keys {
+                router.bind(key, command);
+            }
+        }
+        if let Some(control_scheme) = self.control_scheme_ {
+            router.set_control_scheme(control_scheme);
+        }
+
+        Ok(())
+    }
+
+    fn parse_entry(&mut self, line: &str, line_number: usize) -> Result<(), String> {
+        let mut words = line.split_whitespace();
+        let key = words.next().unwrap();
+        let args: Vec<&str> = words.collect();
+
+        if key == "scheme" {
+            if args.len() != 1 {
+                return Err(format!("'scheme' expects 1 value but got {}", args.len()));
+            }
+            let control_scheme = ControlScheme::from_name(args[0])
+                .ok_or_else(|| format!("unknown control scheme '{}'", args[0]))?;
+            self.control_scheme_ = Some(control_scheme);
+            return Ok(());
+        }
+
+        let command = RouterCommand::from_name(key)
+            .ok_or_else(|| format!("unknown command or entry '{}'", key))?;
+        if !command.is_bindable() {
+            return Err(format!("'{}' can't be bound", key));
+        }
+        if self.keys(command).is_some() {
+            return Err(format!("'{}' is bound more than once", key));
+        }
+        for name in &args {
+            if let Some(other) = self.command(name) {
+                return Err(format!(
+                    "key '{}' is already bound to '{}'",
+                    name,
+                    other.name()
+                ));
+            }
+        }
+
+        self.bindings_.push(KeyBinding {
+            command,
+            keys: args.iter().map(|name| String::from(*name)).collect(),
+            line: line_number,
+        });
+        Ok(())
+    }
+}
+
+impl fmt::Display for Bindings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(control_scheme) = self.control_scheme_ {
+            writeln!(f, "scheme {}", control_scheme.name())?;
+        }
+        for binding in &self.bindings_ {
+            write!(f, "{}", binding.command.name())?;
+            for key in &binding.keys {
+                write!(f, " {}", key)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => err.fmt(f),
+            LoadError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+/// The path of the user's bindings file, `$XDG_CONFIG_HOME/spider/bindings.conf`. Falls back to
+/// `~/.config` if `XDG_CONFIG_HOME` is not set, and returns `None` if neither can be found.
+pub fn user_bindings_path() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_home.join(CONFIG_DIR_NAME).join(BINDINGS_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,196 @@
+use super::*;
+
+fn get_router() -> Router<char> {
+    let mut router = Router::new();
+    router.bind('j', RouterCommand::DOWN);
+    router.bind('k', RouterCommand::UP);
+    router.bind('p', RouterCommand::PAUSE);
+    router
+}
+
+// Single characters name themselves, `space` names ' '.
+fn key_from_name(name: &str) -> Option<char> {
+    match name {
+        "space" => Some(' '),
+        _ if name.chars().count() == 1 => name.chars().next(),
+        _ => None,
+    }
+}
+
+#[test]
+fn bindings_parse() {
+    let text = "
+        # Left-handed.
+        scheme hold
+        DOWN s x
+        STOP
+    ";
+    let bindings = Bindings::parse(text).unwrap();
+
+    assert_eq!(Some(ControlScheme::Hold), bindings.control_scheme());
+    assert_eq!(
+        Some(&[String::from("s"), String::from("x")][..]),
+        bindings.keys(RouterCommand::DOWN)
+    );
+    assert_eq!(Some(&[][..]), bindings.keys(RouterCommand::STOP));
+    assert_eq!(None, bindings.keys(RouterCommand::UP));
+    assert_eq!(4, bindings.bindings()[0].line);
+}
+
+#[test]
+fn bindings_parse_errors() {
+    let error = Bindings::parse("UP w\nJUMP space").unwrap_err();
+    assert_eq!("line 2: unknown command or entry 'JUMP'", error.to_string());
+
+    let error = Bindings::parse("scheme tank").unwrap_err();
+    assert_eq!("line 1: unknown control scheme 'tank'", error.to_string());
+
+    let error = Bindings::parse("scheme").unwrap_err();
+    assert_eq!(
+        "line 1: 'scheme' expects 1 value but got 0",
+        error.to_string()
+    );
+
+    let error = Bindings::parse("UP w\n\nDOWN s w").unwrap_err();
+    assert_eq!(
+        "line 3: key 'w' is already bound to 'UP'",
+        error.to_string()
+    );
+
+    let error = Bindings::parse("UP w\nUP k").unwrap_err();
+    assert_eq!("line 2: 'UP' is bound more than once", error.to_string());
+
+    let error = Bindings::parse("RELEASE_DRAW r").unwrap_err();
+    assert_eq!("line 1: 'RELEASE_DRAW' can't be bound", error.to_string());
+}
+
+#[test]
+fn bindings_round_trip() {
+    let mut bindings = Bindings::new();
+    bindings.set_control_scheme(Some(ControlScheme::Toggle));
+    bindings.set_keys(
+        RouterCommand::LEFT,
+        vec![String::from("a"), String::from("h")],
+    );
+    bindings.set_keys(RouterCommand::PAUSE, Vec::new());
+
+    let text = bindings.to_string();
+    assert_eq!("scheme toggle\nLEFT a h\nPAUSE\n", text);
+
+    let parsed = Bindings::parse(&text).unwrap();
+    assert_eq!(bindings.control_scheme(), parsed.control_scheme());
+    assert_eq!(
+        bindings.keys(RouterCommand::LEFT),
+        parsed.keys(RouterCommand::LEFT)
+    );
+    assert_eq!(
+        bindings.keys(RouterCommand::PAUSE),
+        parsed.keys(RouterCommand::PAUSE)
+    );
+}
+
+#[test]
+fn bindings_command() {
+    let bindings = Bindings::parse("UP w k\nDOWN s").unwrap();
+
+    assert_eq!(Some(RouterCommand::UP), bindings.command("k"));
+    assert_eq!(Some(RouterCommand::DOWN), bindings.command("s"));
+    assert_eq!(None, bindings.command("j"));
+}
+
+#[test]
+fn bindings_add_key() {
+    let mut bindings = Bindings::parse("UP w k\nDOWN s").unwrap();
+
+    assert_eq!(None, bindings.add_key(RouterCommand::DOWN, "j"));
+    assert_eq!(
+        Some(RouterCommand::UP),
+        bindings.add_key(RouterCommand::DOWN, "k")
+    );
+    assert_eq!(
+        Some(RouterCommand::DOWN),
+        bindings.add_key(RouterCommand::DOWN, "s")
+    );
+    assert_eq!(None, bindings.add_key(RouterCommand::LEFT, "a"));
+
+    assert_eq!(
+        Some(&[String::from("w")][..]),
+        bindings.keys(RouterCommand::UP)
+    );
+    assert_eq!(
+        Some(&[String::from("s"), String::from("j"), String::from("k")][..]),
+        bindings.keys(RouterCommand::DOWN)
+    );
+    assert_eq!(
+        Some(&[String::from("a")][..]),
+        bindings.keys(RouterCommand::LEFT)
+    );
+}
+
+#[test]
+fn bindings_from_router() {
+    let mut router = get_router();
+    router.bind('x', RouterCommand::DOWN);
+    router.bind('\n', RouterCommand::STOP);
+
+    let bindings = Bindings::from_router(&router, |key| match key {
+        '\n' => None,
+        _ => Some(key.to_string()),
+    });
+
+    assert_eq!(Some(ControlScheme::Toggle), bindings.control_scheme());
+    assert_eq!(RouterCommand::bindable().count(), bindings.bindings().len());
+    assert_eq!(None, bindings.keys(RouterCommand::RELEASE_DRAW));
+    assert_eq!(
+        Some(&[String::from("j"), String::from("x")][..]),
+        bindings.keys(RouterCommand::DOWN)
+    );
+    assert_eq!(Some(&[][..]), bindings.keys(RouterCommand::STOP));
+    assert_eq!(Some(&[][..]), bindings.keys(RouterCommand::LEFT));
+}
+
+#[test]
+fn bindings_apply_replaces_defaults() {
+    let mut router = get_router();
+    let bindings = Bindings::parse("scheme hold\nDOWN s space\nPAUSE").unwrap();
+
+    bindings.apply(&mut router, key_from_name).unwrap();
+
+    assert_eq!(None, router.command(&'j'));
+    assert_eq!(Some(RouterCommand::DOWN), router.command(&'s'));
+    assert_eq!(Some(RouterCommand::DOWN), router.command(&' '));
+    assert_eq!(Some(RouterCommand::UP), router.command(&'k'));
+    assert_eq!(None, router.command(&'p'));
+    assert_eq!(ControlScheme::Hold, router.control_scheme());
+}
+
+#[test]
+fn bindings_apply_unknown_key() {
+    let mut router = get_router();
+    let bindings = Bindings::parse("UP w\n\nDOWN Down").unwrap();
+
+    let error = bindings.apply(&mut router, key_from_name).unwrap_err();
+
+    assert_eq!("line 3: unknown key 'Down'", error.to_string());
+    assert_eq!(Some(RouterCommand::DOWN), router.command(&'j'));
+    assert_eq!(None, router.command(&'w'));
+}
+
+#[test]
+fn bindings_load_missing_file() {
+    let path = std::env::temp_dir().join("spider-bindings-that-do-not-exist.conf");
+    assert!(Bindings::load(&path).unwrap().is_none());
+}
+
+#[test]
+fn bindings_save_and_load() {
+    let dir = std::env::temp_dir().join(format!("spider-bindings-test-{}", std::process::id()));
+    let path = dir.join(CONFIG_DIR_NAME).join(BINDINGS_FILE_NAME);
+    let bindings = Bindings::parse("scheme hold\nUP w\nDOWN").unwrap();
+
+    bindings.save(&path).unwrap();
+    let loaded = Bindings::load(&path).unwrap().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(bindings.to_string(), loaded.to_string());
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use crate::model::game::Game;
+
+/// The time between two ticks, unless set otherwise with `GameClock::set_tick_duration`.
+pub const DEFAULT_TICK_DURATION: Duration = Duration::from_millis(500);
+
+/// The most ticks a single `GameClock::advance` returns, unless set otherwise with
+/// `GameClock::set_max_catch_up_ticks`.
+pub const DEFAULT_MAX_CATCH_UP_TICKS: u32 = 5;
+
+/// Turns the real time that passes between the frames of a frontend into fixed game ticks, so that
+/// the game runs at the same speed regardless of the frame rate.
+///
+/// Elapsed time is accumulated and one tick is due for every full tick duration. If the frontend
+/// falls far behind, e.g. because it was suspended, at most `max_catch_up_ticks` ticks are due at
+/// once and the remaining time is dropped instead of fast-forwarding the game.
+#[derive(Clone, Debug)]
+pub struct GameClock {
+    tick_duration_: Duration,
+    max_catch_up_ticks_: u32,
+    accumulated_: Duration,
+    paused_: bool,
+    speed_level_: i32,
+}
+
+impl GameClock {
+    pub fn new() -> GameClock {
+        GameClock::with_tick_duration(DEFAULT_TICK_DURATION)
+    }
+
+    pub fn with_tick_duration(tick_duration: Duration) -> GameClock {
+        let mut clock = GameClock {
+            tick_duration_: DEFAULT_TICK_DURATION,
+            max_catch_up_ticks_: DEFAULT_MAX_CATCH_UP_TICKS,
+            accumulated_: Duration::from_secs(0),
+            paused_: false,
+            speed_level_: 0,
+        };
+        clock.set_tick_duration(tick_duration);
+        clock
+    }
+
+    pub fn tick_duration(&self) -> Duration {
+        self.tick_duration_
+    }
+
+    /// Sets the time between two ticks. A zero duration is replaced by one nanosecond.
+    pub fn set_tick_duration(&mut self, tick_duration: Duration) {
+        self.tick_duration_ = tick_duration.max(Duration::from_nanos(1));
+    }
+
+    pub fn speed_level(&self) -> i32 {
+        self.speed_level_
+    }
+
+    /// Sets the speed level, see `Game::speed_level`. Every level above 0 halves the tick duration
+    /// and every level below 0 doubles it. The progress of the next tick is kept.
+    pub fn set_speed_level(&mut self, speed_level: i32) {
+        if speed_level == self.speed_level_ {
+            return;
+        }
+
+        let progress = self.progress();
+        self.speed_level_ = speed_level;
+        self.accumulated_ = self.scaled_tick_duration().mul_f64(progress);
+    }
+
+    pub fn max_catch_up_ticks(&self) -> u32 {
+        self.max_catch_up_ticks_
+    }
+
+    pub fn set_max_catch_up_ticks(&mut self, max_ticks: u32) {
+        self.max_catch_up_ticks_ = max_ticks;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_
+    }
+
+    /// Stops the clock. Time that passes while it is paused doesn't produce ticks.
+    pub fn pause(&mut self) {
+        self.paused_ = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused_ = false;
+    }
+
+    /// Adds the real time that passed since the last call and returns the number of ticks that are
+    /// due.
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        if self.paused_ {
+            return 0;
+        }
+
+        let tick_duration = self.scaled_tick_duration();
+        self.accumulated_ += elapsed;
+        let mut ticks = 0;
+        while self.accumulated_ >= tick_duration {
+            if ticks == self.max_catch_up_ticks_ {
+                self.accumulated_ = Duration::from_secs(0);
+                break;
+            }
+            self.accumulated_ -= tick_duration;
+            ticks += 1;
+        }
+
+        ticks
+    }
+
+    /// The fraction of the next tick that has already passed, in the range [0, 1).
+    pub fn progress(&self) -> f64 {
+        self.accumulated_.as_secs_f64() / self.scaled_tick_duration().as_secs_f64()
+    }
+
+    /// Advances the clock at the speed level of `game` and updates `game` once for every tick that
+    /// is due. Returns the number of updates.
+    pub fn update(&mut self, elapsed: Duration, game: &mut Game) -> u32 {
+        self.set_speed_level(game.speed_level());
+        let ticks = self.advance(elapsed);
+        for _ in 0..ticks {
+            game.update_state();
+        }
+        ticks
+    }
+
+    // The tick duration at the current speed level, at least one nanosecond.
+    fn scaled_tick_duration(&self) -> Duration {
+        let shift = self.speed_level_.unsigned_abs().min(16);
+        let scaled = if self.speed_level_ >= 0 {
+            self.tick_duration_ / (1 << shift)
+        } else {
+            self.tick_duration_ * (1 << shift)
+        };
+        scaled.max(Duration::from_nanos(1))
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> GameClock {
+        GameClock::new()
+    }
+}
+
+#[cfg(test)]
+mod tests;
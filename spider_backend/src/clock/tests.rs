@@ -0,0 +1,82 @@
+use super::*;
+
+use crate::model::config::GameConfig;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn clock_accumulates_ticks() {
+    let mut clock = GameClock::with_tick_duration(ms(100));
+
+    assert_eq!(0, clock.advance(ms(60)));
+    assert_eq!(1, clock.advance(ms(60)));
+    assert_eq!(0, clock.advance(ms(60)));
+    assert_eq!(3, clock.advance(ms(230)));
+    assert!((clock.progress() - 0.1).abs() < 1e-9);
+}
+
+#[test]
+fn clock_limits_catch_up() {
+    let mut clock = GameClock::with_tick_duration(ms(100));
+    clock.set_max_catch_up_ticks(3);
+
+    assert_eq!(3, clock.advance(ms(1050)));
+    // The time that couldn't be caught up with is dropped.
+    assert_eq!(0.0, clock.progress());
+    assert_eq!(1, clock.advance(ms(100)));
+}
+
+#[test]
+fn clock_paused() {
+    let mut clock = GameClock::with_tick_duration(ms(100));
+
+    clock.advance(ms(50));
+    clock.pause();
+    assert!(clock.is_paused());
+    assert_eq!(0, clock.advance(ms(500)));
+
+    clock.resume();
+    assert_eq!(1, clock.advance(ms(50)));
+}
+
+#[test]
+fn clock_zero_tick_duration() {
+    let clock = GameClock::with_tick_duration(ms(0));
+
+    assert!(clock.tick_duration() > ms(0));
+}
+
+#[test]
+fn clock_updates_game() {
+    let mut game = GameConfig::default().build();
+    let mut clock = GameClock::with_tick_duration(ms(100));
+
+    assert_eq!(2, clock.update(ms(250), &mut game));
+    assert_eq!(2, game.tick());
+}
+
+#[test]
+fn clock_speed_level() {
+    let mut clock = GameClock::with_tick_duration(ms(100));
+
+    clock.set_speed_level(1);
+    assert_eq!(2, clock.advance(ms(100)));
+
+    clock.advance(ms(25));
+    clock.set_speed_level(-1);
+    assert!((clock.progress() - 0.5).abs() < 1e-9);
+    assert_eq!(1, clock.advance(ms(100)));
+    assert_eq!(0, clock.advance(ms(100)));
+}
+
+#[test]
+fn clock_updates_at_game_speed_level() {
+    let mut game = GameConfig::default().build();
+    let mut clock = GameClock::with_tick_duration(ms(100));
+    game.handle_speed_up();
+
+    assert_eq!(2, clock.update(ms(100), &mut game));
+    assert_eq!(1, clock.speed_level());
+}
@@ -1,2 +1,9 @@
+pub mod bindings;
+pub mod clock;
 pub mod iter;
 pub mod model;
+pub mod replay;
+pub mod router;
+
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
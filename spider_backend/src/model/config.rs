@@ -0,0 +1,116 @@
+use super::enemy::Enemy;
+use super::field::Field;
+use super::fuse::Fuse;
+use super::game::{Game, DEFAULT_CLAIM_THRESHOLD, DEFAULT_LIVES};
+use super::point::{Direction, Point};
+use super::snake::{Snake, DEFAULT_AGGRESSION};
+use super::sparx::{Orientation, Sparx};
+use super::speed::{Speed, SUBSTEPS_PER_STEP};
+use super::spider::Spider;
+
+/// The configuration of an enemy. The speeds are in sub-steps per tick, see `Speed`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnemyConfig {
+    Snake {
+        pos: Point,
+        aggression: f64,
+        speed: u32,
+    },
+    Sparx {
+        pos: Point,
+        orientation: Orientation,
+        speed: u32,
+    },
+}
+
+impl EnemyConfig {
+    pub fn build(&self) -> Box<dyn Enemy> {
+        match self {
+            EnemyConfig::Snake {
+                pos,
+                aggression,
+                speed,
+            } => {
+                let mut snake = Snake::with_aggression(*pos, *aggression);
+                snake.set_speed(Speed::new(*speed));
+                Box::new(snake)
+            }
+            EnemyConfig::Sparx {
+                pos,
+                orientation,
+                speed,
+            } => {
+                let mut sparx = Sparx::new(*pos, *orientation);
+                sparx.set_speed(Speed::new(*speed));
+                Box::new(sparx)
+            }
+        }
+    }
+}
+
+/// Everything needed to create a game. Games built from the same configuration play out the same
+/// way given the same inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameConfig {
+    pub width: i32,
+    pub height: i32,
+    pub spider_pos: Point,
+    /// The speed of the spider in sub-steps per tick, see `Speed`.
+    pub spider_speed: u32,
+    pub enemies: Vec<EnemyConfig>,
+    pub claim_threshold: i32,
+    pub lives: u32,
+    /// The delay of the fuse in ticks, see `Fuse`, or `None` to play without a fuse.
+    pub fuse_delay: Option<u32>,
+    pub seed: u64,
+}
+
+impl GameConfig {
+    pub fn build(&self) -> Game {
+        let field = Field::new(self.width, self.height);
+        let mut spider = Spider::new(
+            self.spider_pos,
+            Direction::NONE,
+            Point::origin(),
+            Point::new(self.width, self.height),
+        );
+        spider.set_speed(Speed::new(self.spider_speed));
+        let enemies = self.enemies.iter().map(EnemyConfig::build).collect();
+
+        let mut game = Game::with_seed(field, spider, enemies, self.seed);
+        game.set_claim_threshold(self.claim_threshold);
+        game.set_lives(self.lives);
+        game.set_fuse(self.fuse_delay.map(Fuse::new));
+        game
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> GameConfig {
+        GameConfig {
+            width: 50,
+            height: 20,
+            spider_pos: Point::origin(),
+            spider_speed: SUBSTEPS_PER_STEP,
+            enemies: vec![
+                EnemyConfig::Snake {
+                    pos: Point::new(10, 10),
+                    aggression: DEFAULT_AGGRESSION,
+                    speed: SUBSTEPS_PER_STEP,
+                },
+                EnemyConfig::Sparx {
+                    pos: Point::new(50, 20),
+                    orientation: Orientation::Clockwise,
+                    speed: SUBSTEPS_PER_STEP,
+                },
+            ],
+            claim_threshold: DEFAULT_CLAIM_THRESHOLD,
+            lives: DEFAULT_LIVES,
+            fuse_delay: None,
+            seed: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
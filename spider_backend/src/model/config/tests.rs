@@ -0,0 +1,69 @@
+use super::*;
+
+use crate::model::enemy::EnemyKind;
+use crate::model::game::GameState;
+use crate::model::speed::SUBSTEPS_PER_STEP;
+use crate::test_util::get_config;
+
+#[test]
+fn config_build() {
+    let config = GameConfig {
+        claim_threshold: 60,
+        lives: 5,
+        fuse_delay: Some(4),
+        seed: 42,
+        ..get_config()
+    };
+    let game = config.build();
+
+    assert_eq!(10, game.field().width());
+    assert_eq!(4, game.field().height());
+    assert_eq!(&Point::new(5, 0), game.spider().pos());
+    assert_eq!(60, game.claim_threshold());
+    assert_eq!(5, game.lives());
+    assert_eq!(Some(4), game.fuse().map(Fuse::delay_ticks));
+    assert_eq!(42, game.seed());
+    assert_eq!(GameState::Ready, game.state());
+
+    let enemy_positions: Vec<Point> = game.enemies().iter().map(|e| *e.pos()).collect();
+    assert_eq!(vec![Point::new(1, 1), Point::new(10, 4)], enemy_positions);
+    let enemy_kinds: Vec<_> = game.enemies().iter().map(|e| e.kind()).collect();
+    assert_eq!(
+        vec![Some(EnemyKind::Snake), Some(EnemyKind::Sparx)],
+        enemy_kinds
+    );
+}
+
+#[test]
+fn config_build_speeds() {
+    let mut config = get_config();
+    config.spider_speed = 2;
+    if let EnemyConfig::Sparx { speed, .. } = &mut config.enemies[1] {
+        *speed = 6;
+    }
+    let game = config.build();
+
+    assert_eq!(2, game.spider().speed().substeps_per_tick());
+    let enemy_speeds: Vec<u32> = game
+        .enemies()
+        .iter()
+        .map(|e| e.speed().unwrap().substeps_per_tick())
+        .collect();
+    assert_eq!(vec![SUBSTEPS_PER_STEP, 6], enemy_speeds);
+}
+
+#[test]
+fn config_same_config_same_game() {
+    let config = get_config();
+    let mut game1 = config.build();
+    let mut game2 = config.build();
+
+    game1.handle_stop();
+    game2.handle_stop();
+    for _ in 0..20 {
+        game1.update_state();
+        game2.update_state();
+
+        assert_eq!(game1.enemies()[0].pos(), game2.enemies()[0].pos());
+    }
+}
@@ -0,0 +1,103 @@
+use rand::RngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::field::Field;
+use super::point::{Direction, Point};
+use super::rectilinear::Path;
+use super::snake::Snake;
+use super::sparx::Sparx;
+use super::speed::Speed;
+use super::spider::Spider;
+
+/// The set of points an enemy occupies, used for collision detection.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CollisionShape {
+    Point(Point),
+    /// The enemy occupies every point of the path, not just its vertices.
+    Path(Path),
+}
+
+impl CollisionShape {
+    pub fn contains(&self, point: &Point) -> bool {
+        match self {
+            CollisionShape::Point(pos) => pos == point,
+            CollisionShape::Path(path) => path.contains(point),
+        }
+    }
+}
+
+/// The kinds of enemies of this crate, so that frontends can tell them apart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnemyKind {
+    Snake,
+    Sparx,
+}
+
+/// The enemies of this crate as plain data, so that they can be saved and loaded with the rest of
+/// the game.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnemyState {
+    Snake(Snake),
+    Sparx(Sparx),
+}
+
+impl EnemyState {
+    pub fn into_enemy(self) -> Box<dyn Enemy> {
+        match self {
+            EnemyState::Snake(snake) => Box::new(snake),
+            EnemyState::Sparx(sparx) => Box::new(sparx),
+        }
+    }
+}
+
+pub trait Enemy: std::fmt::Debug {
+    fn pos(&self) -> &Point;
+
+    fn set_pos(&mut self, pos: Point);
+
+    /// The direction of the enemy's next unit step. All random decisions must be made with `rng` so
+    /// that games can be reproduced.
+    fn next_step(&self, field: &Field, spider: &Spider, rng: &mut dyn RngCore) -> Direction;
+
+    /// The kind of the enemy, or `None` for enemies that are not defined in this crate.
+    fn kind(&self) -> Option<EnemyKind> {
+        None
+    }
+
+    fn collision_shape(&self) -> CollisionShape {
+        CollisionShape::Point(*self.pos())
+    }
+
+    /// Called by the game once every tick. By default the enemy takes its next step, resolved by
+    /// `Field::resolve_step` so that it stays inside the free polygon.
+    fn update(&mut self, field: &Field, spider: &Spider, rng: &mut dyn RngCore) {
+        let dir = self.next_step(field, spider, rng);
+        let new_pos = field.resolve_step(self.pos(), dir);
+        self.set_pos(new_pos);
+    }
+
+    /// Called by the game after the free polygon of the field has been replaced by a cut. An enemy
+    /// that is left outside the new free polygon stays where it is and is no longer active, see
+    /// `Game::active_enemies`.
+    fn field_changed(&mut self, _field: &Field) {}
+
+    /// The speed of the enemy, or `None` if it takes one step every tick. The game calls `update`
+    /// once for every step that is due.
+    fn speed(&self) -> Option<&Speed> {
+        None
+    }
+
+    fn speed_mut(&mut self) -> Option<&mut Speed> {
+        None
+    }
+
+    /// A copy of the enemy as plain data, or `None` if the enemy can't be saved.
+    fn state(&self) -> Option<EnemyState> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
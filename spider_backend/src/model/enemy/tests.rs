@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn collision_shape_point() {
+    let shape = CollisionShape::Point(Point::new(1, 2));
+
+    assert!(shape.contains(&Point::new(1, 2)));
+    assert!(!shape.contains(&Point::new(2, 1)));
+}
+
+#[test]
+fn collision_shape_path() {
+    let points = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 3)];
+    let path = Path::with_points(points.iter()).unwrap();
+    let shape = CollisionShape::Path(path);
+
+    assert!(shape.contains(&Point::new(0, 0)));
+    assert!(shape.contains(&Point::new(2, 0)));
+    assert!(shape.contains(&Point::new(4, 2)));
+    assert!(!shape.contains(&Point::new(2, 2)));
+}
@@ -0,0 +1,47 @@
+use super::game::DrawMode;
+use super::point::Point;
+use super::rectilinear::Polygon;
+
+/// A notable moment of a game. The game queues its events, see `Game::drain_events`, so that
+/// frontends and tools can react to them without inspecting the game after every update.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameEvent {
+    /// The spider left the edges of the free polygon, starting a path at `start`.
+    PathStarted {
+        start: Point,
+    },
+    /// The spider's path was discarded without claiming any area.
+    PathAbandoned,
+    /// The spider stood still while drawing for too long and the fuse was lit, see `Fuse`.
+    FuseLit,
+    /// The spider finished a path and `polygon` was claimed, which awarded `points`.
+    AreaClaimed {
+        polygon: Polygon,
+        area: i32,
+        points: u32,
+    },
+    /// An enemy caught the spider. Followed by `LifeLost` or `GameOver`.
+    SpiderEaten,
+    LifeLost {
+        lives_left: u32,
+    },
+    GameOver,
+    LevelComplete {
+        bonus: u32,
+    },
+    Paused,
+    Resumed,
+    /// The tick speed changed, see `Game::speed_level`.
+    SpeedChanged {
+        level: i32,
+    },
+    DrawModeChanged {
+        mode: DrawMode,
+    },
+    /// The player asked to start the game over. This and the following requests come from session
+    /// commands, see `RouterCommand::is_session`, and are carried out by the frontend.
+    RestartRequested,
+    QuitRequested,
+    SaveRequested,
+    LoadRequested,
+}
@@ -1,7 +1,11 @@
-use super::point::Point;
+use super::point::{Direction, Point};
 use super::rectilinear;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Field {
     width_: i32,
     height_: i32,
@@ -55,9 +59,70 @@ impl Field {
         self.cut_polygons_.push(cut_polygon);
     }
 
+    /// Returns whether the point is inside or on the edge of the free polygon.
+    pub fn is_free(&self, point: &Point) -> bool {
+        self.free_polygon_.is_inside(point) || self.free_polygon_.is_on_edge(point)
+    }
+
+    /// Returns whether a unit step between two neighbouring points stays within the free polygon.
+    /// A step between two points on the edge of the polygon is only allowed along an edge,
+    /// otherwise it could cross a claimed area.
+    pub fn is_free_step(&self, from: &Point, to: &Point) -> bool {
+        if !self.is_free(from) || !self.is_free(to) {
+            return false;
+        }
+
+        if self.free_polygon_.is_inside(from) || self.free_polygon_.is_inside(to) {
+            return true;
+        }
+
+        self.is_border_step(from, to)
+    }
+
+    /// Returns whether a unit step between two neighbouring points runs along an edge of the free
+    /// polygon.
+    pub fn is_border_step(&self, from: &Point, to: &Point) -> bool {
+        self.free_polygon_
+            .line_iter()
+            .any(|line| line.contains(from) && line.contains(to))
+    }
+
+    /// Returns the position reached by a unit step from `from` in the given direction. A step that
+    /// would leave the free polygon bounces back: the opposite step is taken if it is possible,
+    /// otherwise the position does not change.
+    pub fn resolve_step(&self, from: &Point, dir: Direction) -> Point {
+        for dir in &[dir, dir.opposite()] {
+            let to = from.add(dir.to_point());
+            if self.is_free_step(from, &to) {
+                return to;
+            }
+        }
+
+        *from
+    }
+
     pub fn free_area(&self) -> i32 {
         self.free_polygon_.area()
     }
+
+    /// The area of the whole field, as it was before any cuts.
+    pub fn total_area(&self) -> i32 {
+        self.width_ * self.height_
+    }
+
+    pub fn claimed_area(&self) -> i32 {
+        self.total_area() - self.free_area()
+    }
+
+    /// The claimed area as an integer percentage of the total area.
+    pub fn claimed_percentage(&self) -> i32 {
+        let total_area = self.total_area();
+        if total_area == 0 {
+            return 0;
+        }
+
+        self.claimed_area() * 100 / total_area
+    }
 }
 
 #[cfg(test)]
@@ -1 +1,124 @@
+use super::*;
 
+use crate::test_util::claim_right_half;
+
+#[test]
+fn field_new_nothing_claimed() {
+    let field = Field::new(10, 4);
+
+    assert_eq!(40, field.total_area());
+    assert_eq!(40, field.free_area());
+    assert_eq!(0, field.claimed_area());
+    assert_eq!(0, field.claimed_percentage());
+}
+
+#[test]
+fn field_cut_claimed_area() {
+    let mut field = Field::new(10, 4);
+    claim_right_half(&mut field);
+
+    assert_eq!(40, field.total_area());
+    assert_eq!(20, field.free_area());
+    assert_eq!(20, field.claimed_area());
+    assert_eq!(50, field.claimed_percentage());
+    assert_eq!(1, field.cut_polygons().len());
+}
+
+#[test]
+fn field_is_free() {
+    let mut field = Field::new(10, 4);
+    claim_right_half(&mut field);
+
+    assert!(field.is_free(&Point::new(1, 1)));
+    assert!(field.is_free(&Point::new(5, 1)));
+    assert!(!field.is_free(&Point::new(8, 1)));
+    assert!(!field.is_free(&Point::new(-1, 1)));
+}
+
+#[test]
+fn field_is_free_step() {
+    let field = Field::new(10, 4);
+
+    // Inside.
+    assert!(field.is_free_step(&Point::new(1, 1), &Point::new(2, 1)));
+    // From the edge to the inside.
+    assert!(field.is_free_step(&Point::new(1, 0), &Point::new(1, 1)));
+    // Along an edge.
+    assert!(field.is_free_step(&Point::new(1, 0), &Point::new(2, 0)));
+    // Leaving the field.
+    assert!(!field.is_free_step(&Point::new(1, 0), &Point::new(1, -1)));
+}
+
+#[test]
+fn field_is_free_step_across_claimed_area() {
+    // Claim a strip that is one unit wide. Both sides of the strip are on the edge of the free
+    // polygon but the step between them would cross the claimed area.
+    let mut field = Field::new(10, 4);
+    let points = [
+        Point::new(3, 0),
+        Point::new(3, 3),
+        Point::new(4, 3),
+        Point::new(4, 0),
+    ];
+    let path = rectilinear::Path::with_points(points.iter()).unwrap();
+    let (poly1, poly2) = field.free_polygon().cut(&path).unwrap();
+    if poly1.area() > poly2.area() {
+        field.cut(poly1, poly2);
+    } else {
+        field.cut(poly2, poly1);
+    }
+
+    assert_eq!(3, field.claimed_area());
+    assert!(field.is_free(&Point::new(3, 1)));
+    assert!(field.is_free(&Point::new(4, 1)));
+    assert!(!field.is_free_step(&Point::new(3, 1), &Point::new(4, 1)));
+    assert!(field.is_free_step(&Point::new(3, 3), &Point::new(4, 3)));
+}
+
+#[test]
+fn field_is_border_step() {
+    let mut field = Field::new(10, 4);
+    claim_right_half(&mut field);
+
+    assert!(field.is_border_step(&Point::new(5, 1), &Point::new(5, 2)));
+    assert!(field.is_border_step(&Point::new(5, 0), &Point::new(5, 1)));
+    assert!(!field.is_border_step(&Point::new(5, 1), &Point::new(6, 1)));
+    assert!(!field.is_border_step(&Point::new(5, 1), &Point::new(4, 1)));
+}
+
+#[test]
+fn field_resolve_step_free() {
+    let field = Field::new(10, 4);
+
+    assert_eq!(
+        Point::new(2, 1),
+        field.resolve_step(&Point::new(1, 1), Direction::RIGHT)
+    );
+    assert_eq!(
+        Point::new(1, 1),
+        field.resolve_step(&Point::new(1, 1), Direction::NONE)
+    );
+}
+
+#[test]
+fn field_resolve_step_bounce() {
+    let field = Field::new(10, 4);
+
+    assert_eq!(
+        Point::new(1, 1),
+        field.resolve_step(&Point::new(1, 0), Direction::UP)
+    );
+    assert_eq!(
+        Point::new(9, 2),
+        field.resolve_step(&Point::new(10, 2), Direction::RIGHT)
+    );
+}
+
+#[test]
+fn field_resolve_step_stay() {
+    let field = Field::new(10, 4);
+
+    // Outside the field no step is possible.
+    let outside = Point::new(-5, -5);
+    assert_eq!(outside, field.resolve_step(&outside, Direction::RIGHT));
+}
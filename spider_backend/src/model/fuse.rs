@@ -0,0 +1,93 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::point::{Direction, Point};
+use super::spider::Spider;
+
+/// The number of ticks the spider may stand still while drawing before the fuse is lit, unless
+/// set otherwise with `Fuse::new`.
+pub const DEFAULT_FUSE_DELAY_TICKS: u32 = 6;
+
+/// An optional rule of the game: when the spider stands still in the middle of drawing a path,
+/// a fuse is lit at the start of the path and burns along it towards the spider, one unit per
+/// tick. The spider is caught when the fuse reaches it. Once the spider moves again the fuse
+/// retreats towards the start of the path and goes out there.
+///
+/// The fuse only keeps how far it has burnt, its position follows from the spider's path.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fuse {
+    delay_ticks_: u32,
+    idle_ticks_: u32,
+    burnt_: i32,
+}
+
+impl Fuse {
+    pub fn new(delay_ticks: u32) -> Fuse {
+        Fuse {
+            delay_ticks_: delay_ticks,
+            idle_ticks_: 0,
+            burnt_: 0,
+        }
+    }
+
+    pub fn delay_ticks(&self) -> u32 {
+        self.delay_ticks_
+    }
+
+    pub fn is_lit(&self) -> bool {
+        self.burnt_ > 0
+    }
+
+    /// How far the fuse has burnt along the spider's path, in unit steps from its start.
+    pub fn burnt(&self) -> i32 {
+        self.burnt_
+    }
+
+    /// The position of the fuse on the spider's path, or `None` if the fuse is not lit.
+    pub fn pos(&self, spider: &Spider) -> Option<Point> {
+        if !self.is_lit() {
+            return None;
+        }
+
+        spider.path().and_then(|path| path.point_at(self.burnt_))
+    }
+
+    /// Puts the fuse out, for example when the spider's path has been discarded.
+    pub fn reset(&mut self) {
+        self.idle_ticks_ = 0;
+        self.burnt_ = 0;
+    }
+
+    /// Called by the game once every tick after the spider has moved. Returns whether the fuse
+    /// caught the spider.
+    pub fn update(&mut self, spider: &Spider) -> bool {
+        let path = match spider.path() {
+            Some(path) => path,
+            None => {
+                self.reset();
+                return false;
+            }
+        };
+
+        if spider.get_dir() == Direction::NONE {
+            self.idle_ticks_ = self.idle_ticks_.saturating_add(1);
+        } else {
+            self.idle_ticks_ = 0;
+        }
+
+        if self.idle_ticks_ > self.delay_ticks_ {
+            self.burnt_ += 1;
+        } else {
+            self.burnt_ = (self.burnt_ - 1).max(0);
+        }
+
+        // The path may have become shorter when the spider closed a loop.
+        let length = path.length();
+        self.burnt_ = self.burnt_.min(length);
+        self.is_lit() && self.burnt_ >= length
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,92 @@
+use super::*;
+
+use crate::model::field::Field;
+
+const WIDTH: i32 = 10;
+const HEIGHT: i32 = 10;
+
+// A spider that has drawn a path of `length` units straight down from the upper edge.
+fn get_drawing_spider(length: i32) -> (Field, Spider) {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut spider = Spider::new(
+        Point::new(5, 0),
+        Direction::DOWN,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    spider.set_draw_held(true);
+    for _ in 0..length {
+        spider.step(&field);
+    }
+    (field, spider)
+}
+
+#[test]
+fn fuse_not_lit_while_spider_moves() {
+    let (_, spider) = get_drawing_spider(3);
+    let mut fuse = Fuse::new(0);
+
+    for _ in 0..10 {
+        assert!(!fuse.update(&spider));
+    }
+    assert!(!fuse.is_lit());
+    assert_eq!(None, fuse.pos(&spider));
+}
+
+#[test]
+fn fuse_lit_after_delay() {
+    let (_, mut spider) = get_drawing_spider(3);
+    spider.set_dir(Direction::NONE);
+    let mut fuse = Fuse::new(2);
+
+    fuse.update(&spider);
+    fuse.update(&spider);
+    assert!(!fuse.is_lit());
+
+    fuse.update(&spider);
+    assert!(fuse.is_lit());
+    assert_eq!(Some(Point::new(5, 1)), fuse.pos(&spider));
+}
+
+#[test]
+fn fuse_catches_spider() {
+    let (_, mut spider) = get_drawing_spider(3);
+    spider.set_dir(Direction::NONE);
+    let mut fuse = Fuse::new(0);
+
+    assert!(!fuse.update(&spider));
+    assert!(!fuse.update(&spider));
+    assert!(fuse.update(&spider));
+    assert_eq!(Some(*spider.pos()), fuse.pos(&spider));
+}
+
+#[test]
+fn fuse_retreats_when_spider_moves() {
+    let (field, mut spider) = get_drawing_spider(3);
+    spider.set_dir(Direction::NONE);
+    let mut fuse = Fuse::new(0);
+    fuse.update(&spider);
+    fuse.update(&spider);
+    assert_eq!(2, fuse.burnt());
+
+    spider.set_dir(Direction::LEFT);
+    spider.step(&field);
+    assert!(!fuse.update(&spider));
+    assert_eq!(1, fuse.burnt());
+
+    spider.step(&field);
+    fuse.update(&spider);
+    assert!(!fuse.is_lit());
+}
+
+#[test]
+fn fuse_out_without_path() {
+    let (_, mut spider) = get_drawing_spider(3);
+    spider.set_dir(Direction::NONE);
+    let mut fuse = Fuse::new(0);
+    fuse.update(&spider);
+
+    spider.stop_path();
+    assert!(!fuse.update(&spider));
+    assert!(!fuse.is_lit());
+}
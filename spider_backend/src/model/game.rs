@@ -1,25 +1,144 @@
+use std::collections::VecDeque;
+
+use rand::SeedableRng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::enemy::Enemy;
+use super::event::GameEvent;
 use super::field::Field;
-use super::point::Direction;
-use super::snake::Snake;
+use super::fuse::Fuse;
+use super::point::{Direction, Point};
+use super::rectilinear::{Line, LineIntersection, Polygon};
+use super::score::Score;
+use super::speed::Speed;
 use super::spider::Spider;
+use crate::router::{self, RouterCommand, TimedCommand};
+
+/// The percentage of the field that has to be claimed to complete the level, unless set otherwise
+/// with `Game::set_claim_threshold`.
+pub const DEFAULT_CLAIM_THRESHOLD: i32 = 75;
+
+/// The random number generator used for all random decisions in a game.
+pub type GameRng = rand_pcg::Pcg32;
+
+/// The number of lives the spider has at the start of the game, unless set otherwise with
+/// `Game::set_lives`.
+pub const DEFAULT_LIVES: u32 = 3;
+
+/// The number of ticks the spider cannot be caught for after it has lost a life.
+pub const INVULNERABLE_TICKS: u32 = 10;
+
+/// The most direction commands that wait in the input queue, see `Game::queue_command`.
+pub const MAX_QUEUED_DIRECTIONS: usize = 2;
+
+/// The slowest tick speed, see `Game::speed_level`.
+pub const MIN_SPEED_LEVEL: i32 = -2;
+
+/// The fastest tick speed, see `Game::speed_level`.
+pub const MAX_SPEED_LEVEL: i32 = 2;
+
+/// How the spider draws its paths.
+///
+/// * `Fast`: the spider draws at its normal speed.
+/// * `Slow`: the spider draws at half its speed, but the claimed area is awarded
+///   `score::SLOW_DRAW_MULTIPLIER` times the points.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DrawMode {
+    Fast,
+    Slow,
+}
+
+/// The state of a game.
+///
+/// * `Ready`: nothing moves until the first direction command, which starts the game.
+/// * `Running`: `Game::update_state` moves the enemies and the spider.
+/// * `Paused`: `Game::update_state` does nothing and only the pause command is accepted.
+/// * `LifeLost`: the spider has been caught but still has lives left. It has been put back on the
+///   edge of the free polygon and nothing moves until the next direction command.
+/// * `LevelComplete` and `GameOver`: final states, all updates and commands are ignored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameState {
+    Ready,
+    Running,
+    Paused,
+    LifeLost,
+    LevelComplete,
+    GameOver,
+}
+
+impl GameState {
+    pub fn can_transition_to(&self, new_state: GameState) -> bool {
+        use GameState::*;
+
+        matches!(
+            (self, new_state),
+            (Ready, Running)
+                | (Running, Paused)
+                | (Running, LevelComplete)
+                | (Running, LifeLost)
+                | (Running, GameOver)
+                | (Paused, Running)
+                | (LifeLost, Running)
+        )
+    }
+}
 
 #[derive(Debug)]
 pub struct Game {
     field_: Field,
     spider_: Spider,
-    snake_: Snake,
-    paused_: bool,
-    game_over_: bool,
+    enemies_: Vec<Box<dyn Enemy>>,
+    fuse_: Option<Fuse>,
+    claim_threshold_: i32,
+    lives_: u32,
+    invulnerable_ticks_: u32,
+    score_: Score,
+    draw_mode_: DrawMode,
+    speed_level_: i32,
+    state_: GameState,
+    seed_: u64,
+    rng_: GameRng,
+    tick_: u64,
+    command_log_: Vec<TimedCommand>,
+    input_queue_: VecDeque<RouterCommand>,
+    events_: Vec<GameEvent>,
 }
 
 impl Game {
-    pub fn new(field: Field, spider: Spider, snake: Snake) -> Game {
+    /// Creates a game with a random seed.
+    pub fn new(field: Field, spider: Spider, enemies: Vec<Box<dyn Enemy>>) -> Game {
+        Game::with_seed(field, spider, enemies, rand::random())
+    }
+
+    /// Creates a game whose random decisions are all derived from `seed`. Games with the same seed
+    /// and the same inputs play out the same way.
+    pub fn with_seed(
+        field: Field,
+        spider: Spider,
+        enemies: Vec<Box<dyn Enemy>>,
+        seed: u64,
+    ) -> Game {
         Game {
             field_: field,
             spider_: spider,
-            snake_: snake,
-            paused_: false,
-            game_over_: false,
+            enemies_: enemies,
+            fuse_: None,
+            claim_threshold_: DEFAULT_CLAIM_THRESHOLD,
+            lives_: DEFAULT_LIVES,
+            invulnerable_ticks_: 0,
+            score_: Score::new(),
+            draw_mode_: DrawMode::Fast,
+            speed_level_: 0,
+            state_: GameState::Ready,
+            seed_: seed,
+            rng_: GameRng::seed_from_u64(seed),
+            tick_: 0,
+            command_log_: Vec::new(),
+            input_queue_: VecDeque::new(),
+            events_: Vec::new(),
         }
     }
 
@@ -31,85 +150,595 @@ impl Game {
         &self.spider_
     }
 
-    pub fn snake(&self) -> &Snake {
-        &self.snake_
+    pub fn enemies(&self) -> &[Box<dyn Enemy>] {
+        &self.enemies_
+    }
+
+    /// The enemies inside or on the edge of the free polygon. An enemy that a cut leaves in the
+    /// claimed area stays in `enemies`, but it no longer moves, catches the spider or keeps the
+    /// spider away when it respawns.
+    pub fn active_enemies(&self) -> impl Iterator<Item = &dyn Enemy> {
+        let field = &self.field_;
+        self.enemies_
+            .iter()
+            .map(|enemy| enemy.as_ref())
+            .filter(move |enemy| field.is_free(enemy.pos()))
+    }
+
+    /// The fuse that burns along the spider's path, or `None` if the fuse rule is not used.
+    pub fn fuse(&self) -> Option<&Fuse> {
+        self.fuse_.as_ref()
+    }
+
+    /// Turns the fuse rule on or off. Games start without a fuse.
+    pub fn set_fuse(&mut self, fuse: Option<Fuse>) {
+        self.fuse_ = fuse;
+    }
+
+    pub fn claim_threshold(&self) -> i32 {
+        self.claim_threshold_
+    }
+
+    /// Sets the percentage of the field's original area that has to be claimed to complete the
+    /// level. The value is clamped to the range [0, 100].
+    pub fn set_claim_threshold(&mut self, percent: i32) {
+        self.claim_threshold_ = percent.clamp(0, 100);
+    }
+
+    pub fn lives(&self) -> u32 {
+        self.lives_
+    }
+
+    pub fn set_lives(&mut self, lives: u32) {
+        self.lives_ = lives;
+    }
+
+    /// The number of remaining ticks during which the spider cannot be caught.
+    pub fn invulnerable_ticks(&self) -> u32 {
+        self.invulnerable_ticks_
+    }
+
+    pub fn score(&self) -> &Score {
+        &self.score_
+    }
+
+    pub fn draw_mode(&self) -> DrawMode {
+        self.draw_mode_
+    }
+
+    /// How fast the game should tick, from `MIN_SPEED_LEVEL` to `MAX_SPEED_LEVEL`. Every level
+    /// doubles the speed of the level below, 0 is the normal speed. The game only keeps the level;
+    /// the ticks are timed by the frontend, see `GameClock::set_speed_level`.
+    pub fn speed_level(&self) -> i32 {
+        self.speed_level_
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed_
+    }
+
+    pub fn state(&self) -> GameState {
+        self.state_
+    }
+
+    /// The number of times `update_state` has been called, including the calls while the game
+    /// was not running.
+    pub fn tick(&self) -> u64 {
+        self.tick_
+    }
+
+    /// All commands handled by `handle_command` except session commands, stamped with the tick they
+    /// took effect on.
+    pub fn command_log(&self) -> &[TimedCommand] {
+        &self.command_log_
+    }
+
+    /// The events that happened since they were last drained, oldest first.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events_
+    }
+
+    /// Removes and returns the queued events. Frontends should drain the events regularly, for
+    /// example after every frame, as the queue is not bounded.
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, GameEvent> {
+        self.events_.drain(..)
+    }
+
+    /// Queues a command to be applied at the start of the next `update_state`, which is how
+    /// frontends give input. Commands are applied in the order they were queued, but at most one
+    /// direction command per tick, so that quick taps between two ticks are not lost. Other
+    /// commands don't wait for the directions ahead of them.
+    ///
+    /// A direction that wouldn't change the direction before it is dropped, so that held keys
+    /// repeating don't pile up. Of more than `MAX_QUEUED_DIRECTIONS` directions the oldest ones
+    /// are dropped.
+    pub fn queue_command(&mut self, command: RouterCommand) {
+        if !command.is_direction() {
+            self.input_queue_.push_back(command);
+            return;
+        }
+
+        let last_direction = self
+            .input_queue_
+            .iter()
+            .rev()
+            .find(|queued| queued.is_direction());
+        let redundant = match last_direction {
+            Some(last_direction) => *last_direction == command,
+            None => {
+                self.state_ == GameState::Running
+                    && command_direction(command) == Some(self.spider_.get_dir())
+            }
+        };
+        if redundant {
+            return;
+        }
+
+        self.input_queue_.push_back(command);
+        let directions = self
+            .input_queue_
+            .iter()
+            .filter(|queued| queued.is_direction());
+        if directions.count() > MAX_QUEUED_DIRECTIONS {
+            let oldest = self
+                .input_queue_
+                .iter()
+                .position(|queued| queued.is_direction())
+                .unwrap();
+            self.input_queue_.remove(oldest);
+        }
+    }
+
+    /// The commands waiting to be applied, oldest first.
+    pub fn queued_commands(&self) -> &VecDeque<RouterCommand> {
+        &self.input_queue_
+    }
+
+    /// Applies a command right away, bypassing the input queue. Used to replay logged commands on
+    /// the ticks they took effect on.
+    pub fn handle_command(&mut self, command: RouterCommand) {
+        if !command.is_session() {
+            self.command_log_.push(TimedCommand {
+                tick: self.tick_,
+                command,
+            });
+        }
+
+        let handler = router::command_to_handler(command);
+        handler(self);
     }
 
     pub fn handle_up(&mut self) {
-        self.spider_.set_dir(Direction::UP);
+        self.handle_direction(Direction::UP);
     }
 
     pub fn handle_down(&mut self) {
-        self.spider_.set_dir(Direction::DOWN);
+        self.handle_direction(Direction::DOWN);
     }
 
     pub fn handle_left(&mut self) {
-        self.spider_.set_dir(Direction::LEFT);
+        self.handle_direction(Direction::LEFT);
     }
 
     pub fn handle_right(&mut self) {
-        self.spider_.set_dir(Direction::RIGHT);
+        self.handle_direction(Direction::RIGHT);
     }
 
     pub fn handle_stop(&mut self) {
-        self.spider_.set_dir(Direction::NONE);
+        self.handle_direction(Direction::NONE);
+    }
+
+    /// Holds the draw command, which lets the spider leave the border into the free polygon.
+    pub fn handle_draw(&mut self) {
+        self.spider_.set_draw_held(true);
+    }
+
+    pub fn handle_release_draw(&mut self) {
+        self.spider_.set_draw_held(false);
     }
 
     pub fn handle_pause(&mut self) {
-        self.paused_ = !self.paused_;
+        let (new_state, event) = match self.state_ {
+            GameState::Running => (GameState::Paused, GameEvent::Paused),
+            GameState::Paused => (GameState::Running, GameEvent::Resumed),
+            _ => return,
+        };
+
+        self.transition(new_state);
+        self.events_.push(event);
+    }
+
+    /// Switches between fast and slow draw mode. Ignored while the spider is drawing, so that a
+    /// path is drawn in a single mode.
+    pub fn handle_toggle_draw_mode(&mut self) {
+        if self.spider_.is_drawing() {
+            return;
+        }
+
+        self.draw_mode_ = match self.draw_mode_ {
+            DrawMode::Fast => DrawMode::Slow,
+            DrawMode::Slow => DrawMode::Fast,
+        };
+        self.events_.push(GameEvent::DrawModeChanged {
+            mode: self.draw_mode_,
+        });
+    }
+
+    pub fn handle_speed_up(&mut self) {
+        self.change_speed_level(1);
+    }
+
+    pub fn handle_slow_down(&mut self) {
+        self.change_speed_level(-1);
+    }
+
+    pub fn handle_restart(&mut self) {
+        self.events_.push(GameEvent::RestartRequested);
+    }
+
+    pub fn handle_quit(&mut self) {
+        self.events_.push(GameEvent::QuitRequested);
+    }
+
+    pub fn handle_save(&mut self) {
+        self.events_.push(GameEvent::SaveRequested);
+    }
+
+    pub fn handle_load(&mut self) {
+        self.events_.push(GameEvent::LoadRequested);
     }
 
     pub fn update_state(&mut self) {
-        if self.game_over_ || self.paused_ {
+        self.apply_queued_commands();
+        self.tick_ += 1;
+
+        if self.state_ != GameState::Running {
             return;
         }
-        // Update the snake position.
-        self.update_snake();
 
-        // Detect spider starting or ending path.
-        self.update_spider();
+        let vulnerable = self.invulnerable_ticks_ == 0;
+        self.invulnerable_ticks_ = self.invulnerable_ticks_.saturating_sub(1);
+
+        // Entities that take several steps in a tick take them in separate phases of the tick, so
+        // that nothing moves more than one unit per phase.
+        let steps = self.tick_steps();
+        for phase in 0..steps.phases {
+            // The enemies and the spider all decide their moves based on the state at the start of
+            // the phase and collisions are checked along the segments they travelled, so the order
+            // of the updates does not matter.
+            let spider_start = *self.spider_.pos();
+
+            // Update the enemy positions.
+            let enemy_moves = self.update_enemies(&steps, phase);
+
+            // Detect spider starting or ending path.
+            if steps.moves_in_phase(steps.spider, phase) {
+                self.update_spider();
+            }
+
+            // Detect an enemy eating the spider.
+            let spider_move = Line::from_points(spider_start, *self.spider_.pos())
+                .expect("Should not happen, the spider only takes rectilinear steps.");
+            if vulnerable && self.spider_caught(&spider_move, &enemy_moves) {
+                self.handle_spider_eaten();
+                return;
+            }
+        }
 
-        // Detect snake eating spider.
-        if self.spider().pos() == self.snake().pos() {
+        // Detect the fuse reaching the spider.
+        if self.update_fuse() && vulnerable {
             self.handle_spider_eaten();
             return;
         }
 
         // Detect winning.
-        // TODO.
+        if self.field_.claimed_percentage() >= self.claim_threshold_ {
+            self.handle_level_complete();
+        }
+    }
+
+    // Applies the first queued direction and all other commands. Later directions wait for the
+    // following ticks.
+    fn apply_queued_commands(&mut self) {
+        let mut applied_direction = false;
+        let mut waiting = VecDeque::new();
+        while let Some(command) = self.input_queue_.pop_front() {
+            if command.is_direction() {
+                if applied_direction {
+                    waiting.push_back(command);
+                    continue;
+                }
+                applied_direction = true;
+            }
+            self.handle_command(command);
+        }
+        self.input_queue_ = waiting;
+    }
+
+    fn handle_direction(&mut self, dir: Direction) {
+        match self.state_ {
+            GameState::Ready | GameState::LifeLost => {
+                self.transition(GameState::Running);
+                self.spider_.set_dir(dir);
+            }
+            GameState::Running => self.spider_.set_dir(dir),
+            _ => {}
+        }
+    }
+
+    fn change_speed_level(&mut self, delta: i32) {
+        let level = (self.speed_level_ + delta).clamp(MIN_SPEED_LEVEL, MAX_SPEED_LEVEL);
+        if level != self.speed_level_ {
+            self.speed_level_ = level;
+            self.events_.push(GameEvent::SpeedChanged { level });
+        }
     }
 
-    fn update_snake(&mut self) {
-        let dir = self.snake_.next_step(&self.field_, &self.spider_);
-        let new_pos = self.snake_.pos().add(dir.to_point());
-        self.snake_.set_pos(new_pos);
+    // Transitions that are not allowed from the current state are ignored.
+    fn transition(&mut self, new_state: GameState) {
+        if self.state_.can_transition_to(new_state) {
+            self.state_ = new_state;
+        }
+    }
+
+    // Advances the speeds of the spider and the enemies by one tick. In slow draw mode the spider
+    // moves at half its speed while it is drawing.
+    fn tick_steps(&mut self) -> TickSteps {
+        let slow_drawing = self.draw_mode_ == DrawMode::Slow && self.spider_.is_drawing();
+        let speed = self.spider_.speed_mut();
+        let substeps_per_tick = speed.substeps_per_tick();
+        if slow_drawing {
+            // Rounded up, so that a very slow spider still moves.
+            speed.set_substeps_per_tick(substeps_per_tick / 2 + substeps_per_tick % 2);
+        }
+        let spider = speed.tick();
+        speed.set_substeps_per_tick(substeps_per_tick);
+        let enemies: Vec<u32> = self
+            .enemies_
+            .iter_mut()
+            .map(|enemy| enemy.speed_mut().map_or(1, Speed::tick))
+            .collect();
+        let phases = enemies.iter().copied().fold(spider, u32::max).max(1);
+
+        TickSteps {
+            spider,
+            enemies,
+            phases,
+        }
+    }
+
+    // Returns the segments the enemies travelled in the phase. Moves that are not rectilinear, for
+    // example when an enemy is put back on the field, are left out.
+    fn update_enemies(&mut self, steps: &TickSteps, phase: u32) -> Vec<Line<Point>> {
+        let mut moves = Vec::with_capacity(self.enemies_.len());
+        for (enemy, enemy_steps) in self.enemies_.iter_mut().zip(&steps.enemies) {
+            let start = *enemy.pos();
+            if !steps.moves_in_phase(*enemy_steps, phase) || !self.field_.is_free(&start) {
+                moves.push(Line::from_points(start, start).unwrap());
+                continue;
+            }
+
+            enemy.update(&self.field_, &self.spider_, &mut self.rng_);
+
+            if let Some(line) = Line::from_points(start, *enemy.pos()) {
+                moves.push(line);
+            }
+        }
+
+        moves
+    }
+
+    // Returns whether the fuse caught the spider.
+    fn update_fuse(&mut self) -> bool {
+        let fuse = match &mut self.fuse_ {
+            Some(fuse) => fuse,
+            None => return false,
+        };
+
+        let was_lit = fuse.is_lit();
+        let caught = fuse.update(&self.spider_);
+        if !was_lit && fuse.is_lit() {
+            self.events_.push(GameEvent::FuseLit);
+        }
+        caught
+    }
+
+    // The spider is caught if an enemy touches it, met it while moving or lands on its unfinished
+    // path.
+    fn spider_caught(&self, spider_move: &Line<Point>, enemy_moves: &[Line<Point>]) -> bool {
+        let spider_pos = self.spider_.pos();
+        let path = self.spider_.path();
+
+        let touched = self.active_enemies().any(|enemy| {
+            enemy.collision_shape().contains(spider_pos)
+                || path.is_some_and(|path| path.contains(enemy.pos()))
+        });
+
+        touched
+            || enemy_moves
+                .iter()
+                .any(|enemy_move| moves_collide(spider_move, enemy_move))
     }
 
     fn update_spider(&mut self) {
-        let free_polygon = self.field_.free_polygon();
-        if !free_polygon.is_inside(self.spider_.pos()) {
-            self.spider_.start_path();
-        }
-
-        self.spider_.update();
-
-        if !free_polygon.is_inside(self.spider_.pos()) {
-            let path = self.spider_.stop_path();
-            if let Some(path) = path {
-                if let Some((poly1, poly2)) = free_polygon.cut(&path) {
-                    let snake_point = self.snake().pos();
-                    if poly1.is_inside(snake_point) {
-                        self.field_.cut(poly1, poly2);
-                    } else {
-                        self.field_.cut(poly2, poly1);
-                    }
+        let start = *self.spider_.pos();
+        let was_drawing = self.spider_.is_drawing();
+
+        let path = self.spider_.step(&self.field_);
+
+        if !was_drawing && self.spider_.is_drawing() {
+            self.events_.push(GameEvent::PathStarted { start });
+        }
+
+        if let Some(path) = path {
+            if let Some((poly1, poly2)) = self.field_.free_polygon().cut(&path) {
+                let (new_free_polygon, cut_polygon) = if self.keep_free(&poly1, &poly2) {
+                    (poly1, poly2)
+                } else {
+                    (poly2, poly1)
+                };
+
+                let total_area = self.field_.total_area();
+                let area = cut_polygon.area();
+                let points = match self.draw_mode_ {
+                    DrawMode::Fast => self.score_.add_claimed_area(area, total_area),
+                    DrawMode::Slow => self.score_.add_slow_claimed_area(area, total_area),
+                };
+                self.events_.push(GameEvent::AreaClaimed {
+                    polygon: cut_polygon.clone(),
+                    area,
+                    points,
+                });
+                self.field_.cut(new_free_polygon, cut_polygon);
+
+                for enemy in self.enemies_.iter_mut() {
+                    enemy.field_changed(&self.field_);
                 }
+            } else {
+                // The path returned to the edges but didn't cut the free polygon.
+                self.events_.push(GameEvent::PathAbandoned);
             }
         }
     }
 
+    // Returns whether `poly1` should remain free after a cut instead of `poly2`. The polygon with
+    // more enemies inside it remains free, or the larger one if they have the same number of
+    // enemies.
+    fn keep_free(&self, poly1: &Polygon, poly2: &Polygon) -> bool {
+        let enemies_inside = |poly: &Polygon| {
+            self.enemies_
+                .iter()
+                .filter(|enemy| poly.is_inside(enemy.pos()))
+                .count()
+        };
+
+        let (count1, count2) = (enemies_inside(poly1), enemies_inside(poly2));
+        if count1 != count2 {
+            count1 > count2
+        } else {
+            poly1.area() >= poly2.area()
+        }
+    }
+
     fn handle_spider_eaten(&mut self) {
-        self.game_over_ = true;
-        println!("Game over.");
+        self.events_.push(GameEvent::SpiderEaten);
+        self.lives_ = self.lives_.saturating_sub(1);
+        if self.lives_ == 0 {
+            self.transition(GameState::GameOver);
+            self.events_.push(GameEvent::GameOver);
+            return;
+        }
+
+        self.respawn_spider();
+        self.transition(GameState::LifeLost);
+        self.events_.push(GameEvent::LifeLost {
+            lives_left: self.lives_,
+        });
+    }
+
+    // Puts the spider on the vertex of the free polygon that is the farthest away from the
+    // closest enemy, discards its unfinished path and makes it invulnerable for a while.
+    fn respawn_spider(&mut self) {
+        let distance_to_closest_enemy = |point: &Point| {
+            self.active_enemies()
+                .map(|enemy| manhattan_distance(point, enemy.pos()))
+                .min()
+                .unwrap_or(0)
+        };
+
+        let safe_point = self
+            .field_
+            .free_polygon()
+            .path()
+            .points()
+            .iter()
+            .max_by_key(|point| distance_to_closest_enemy(point))
+            .copied();
+
+        if self.spider_.stop_path().is_some() {
+            self.events_.push(GameEvent::PathAbandoned);
+        }
+        if let Some(fuse) = &mut self.fuse_ {
+            fuse.reset();
+        }
+        self.spider_.set_dir(Direction::NONE);
+        if let Some(point) = safe_point {
+            self.spider_.set_pos(point);
+        }
+
+        self.invulnerable_ticks_ = INVULNERABLE_TICKS;
+    }
+
+    fn handle_level_complete(&mut self) {
+        let claimed_percentage = self.field_.claimed_percentage();
+        let bonus = self
+            .score_
+            .add_level_complete_bonus(claimed_percentage, self.claim_threshold_);
+        self.transition(GameState::LevelComplete);
+        self.events_.push(GameEvent::LevelComplete { bonus });
+    }
+}
+
+// The number of unit steps the spider and each of the enemies take in a tick, spread evenly over
+// the phases of the tick.
+struct TickSteps {
+    spider: u32,
+    enemies: Vec<u32>,
+    phases: u32,
+}
+
+impl TickSteps {
+    fn moves_in_phase(&self, steps: u32, phase: u32) -> bool {
+        (phase + 1) * steps / self.phases > phase * steps / self.phases
+    }
+}
+
+// The direction a direction command steers the spider in.
+fn command_direction(command: RouterCommand) -> Option<Direction> {
+    match command {
+        RouterCommand::UP => Some(Direction::UP),
+        RouterCommand::DOWN => Some(Direction::DOWN),
+        RouterCommand::LEFT => Some(Direction::LEFT),
+        RouterCommand::RIGHT => Some(Direction::RIGHT),
+        RouterCommand::STOP => Some(Direction::NONE),
+        _ => None,
+    }
+}
+
+// Returns whether two entities that travel along the given segments at constant speed during the
+// same phase of a tick meet. Entities moving along overlapping segments always meet because they
+// take at most unit steps, so they either move together or swap positions.
+fn moves_collide(move1: &Line<Point>, move2: &Line<Point>) -> bool {
+    match move1.intersection(move2) {
+        None => false,
+        Some(LineIntersection::Line(_)) => true,
+        Some(LineIntersection::Point(point)) => {
+            let (start1, end1) = time_at(move1, &point);
+            let (start2, end2) = time_at(move2, &point);
+            start1.max(start2) <= end1.min(end2)
+        }
     }
 }
+
+// The time interval within the tick, scaled to [0, 1], during which an entity moving along the
+// segment is at the given point of the segment.
+fn time_at(segment: &Line<Point>, point: &Point) -> (f64, f64) {
+    let len = manhattan_distance(segment.start(), segment.end());
+    if len == 0 {
+        return (0.0, 1.0);
+    }
+
+    let time = manhattan_distance(segment.start(), point) as f64 / len as f64;
+    (time, time)
+}
+
+fn manhattan_distance(p1: &Point, p2: &Point) -> i32 {
+    let diff = p1.subtract(p2);
+    diff.x.abs() + diff.y.abs()
+}
+
+#[cfg(feature = "serde")]
+mod save;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{DrawMode, Game, GameRng, GameState};
+use crate::model::enemy::EnemyState;
+use crate::model::field::Field;
+use crate::model::fuse::Fuse;
+use crate::model::score::Score;
+use crate::model::spider::Spider;
+use crate::router::{RouterCommand, TimedCommand};
+
+// The saved form of a game. Enemies are stored as their `EnemyState`, everything else as is.
+#[derive(Serialize)]
+struct SavedGameRef<'a> {
+    field: &'a Field,
+    spider: &'a Spider,
+    enemies: Vec<EnemyState>,
+    fuse: Option<&'a Fuse>,
+    claim_threshold: i32,
+    lives: u32,
+    invulnerable_ticks: u32,
+    score: &'a Score,
+    draw_mode: DrawMode,
+    speed_level: i32,
+    state: GameState,
+    seed: u64,
+    rng: &'a GameRng,
+    tick: u64,
+    command_log: &'a [TimedCommand],
+    input_queue: &'a VecDeque<RouterCommand>,
+}
+
+#[derive(Deserialize)]
+struct SavedGame {
+    field: Field,
+    spider: Spider,
+    enemies: Vec<EnemyState>,
+    fuse: Option<Fuse>,
+    claim_threshold: i32,
+    lives: u32,
+    invulnerable_ticks: u32,
+    score: Score,
+    draw_mode: DrawMode,
+    speed_level: i32,
+    state: GameState,
+    seed: u64,
+    rng: GameRng,
+    tick: u64,
+    command_log: Vec<TimedCommand>,
+    input_queue: VecDeque<RouterCommand>,
+}
+
+/// Saves the complete state of the game, including the state of its RNG, so that a loaded game
+/// continues exactly like the original. Fails for enemies without an `EnemyState`.
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let enemies = self
+            .enemies_
+            .iter()
+            .map(|enemy| {
+                enemy
+                    .state()
+                    .ok_or_else(|| ser::Error::custom(format!("cannot save enemy {:?}", enemy)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        SavedGameRef {
+            field: &self.field_,
+            spider: &self.spider_,
+            enemies,
+            fuse: self.fuse_.as_ref(),
+            claim_threshold: self.claim_threshold_,
+            lives: self.lives_,
+            invulnerable_ticks: self.invulnerable_ticks_,
+            score: &self.score_,
+            draw_mode: self.draw_mode_,
+            speed_level: self.speed_level_,
+            state: self.state_,
+            seed: self.seed_,
+            rng: &self.rng_,
+            tick: self.tick_,
+            command_log: &self.command_log_,
+            input_queue: &self.input_queue_,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Game, D::Error> {
+        let saved = SavedGame::deserialize(deserializer)?;
+
+        Ok(Game {
+            field_: saved.field,
+            spider_: saved.spider,
+            enemies_: saved
+                .enemies
+                .into_iter()
+                .map(EnemyState::into_enemy)
+                .collect(),
+            fuse_: saved.fuse,
+            claim_threshold_: saved.claim_threshold,
+            lives_: saved.lives,
+            invulnerable_ticks_: saved.invulnerable_ticks,
+            score_: saved.score,
+            draw_mode_: saved.draw_mode,
+            speed_level_: saved.speed_level,
+            state_: saved.state,
+            seed_: saved.seed,
+            rng_: saved.rng,
+            tick_: saved.tick,
+            command_log_: saved.command_log,
+            input_queue_: saved.input_queue,
+            events_: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,117 @@
+use crate::model::config::GameConfig;
+use crate::model::enemy::Enemy;
+use crate::model::field::Field;
+use crate::model::game::Game;
+use crate::model::point::{Direction, Point};
+use crate::model::rectilinear::Polygon;
+use crate::model::spider::Spider;
+use crate::router::RouterCommand;
+use crate::test_util;
+
+use rand::RngCore;
+
+// Uses the fuse rule, so that the saves hold the fuse.
+fn get_game() -> Game {
+    GameConfig {
+        fuse_delay: Some(3),
+        ..test_util::get_config()
+    }
+    .build()
+}
+
+fn save(game: &Game) -> String {
+    serde_json::to_string(game).unwrap()
+}
+
+fn load(json: &str) -> Game {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn save_load_round_trip() {
+    let mut game = get_game();
+    game.handle_command(RouterCommand::DRAW);
+    game.handle_command(RouterCommand::DOWN);
+    game.update_state();
+    game.update_state();
+
+    let loaded = load(&save(&game));
+
+    assert_eq!(save(&game), save(&loaded));
+    assert_eq!(game.state(), loaded.state());
+    assert_eq!(game.tick(), loaded.tick());
+    assert_eq!(game.spider().pos(), loaded.spider().pos());
+    assert_eq!(game.spider().state(), loaded.spider().state());
+    assert_eq!(game.spider().is_draw_held(), loaded.spider().is_draw_held());
+    assert_eq!(game.field().free_polygon(), loaded.field().free_polygon());
+    assert_eq!(game.command_log(), loaded.command_log());
+}
+
+#[test]
+fn save_load_resumes_identically() {
+    let mut game = get_game();
+    game.handle_command(RouterCommand::DRAW);
+    game.handle_command(RouterCommand::DOWN);
+    for _ in 0..5 {
+        game.update_state();
+    }
+    assert_eq!(1, game.field().cut_polygons().len());
+
+    let mut loaded = load(&save(&game));
+    for game in [&mut game, &mut loaded].iter_mut() {
+        game.handle_command(RouterCommand::LEFT);
+        for _ in 0..20 {
+            game.update_state();
+        }
+    }
+
+    assert_eq!(save(&game), save(&loaded));
+}
+
+#[test]
+fn save_fails_for_unknown_enemy() {
+    #[derive(Debug)]
+    struct StillEnemy(Point);
+
+    impl Enemy for StillEnemy {
+        fn pos(&self) -> &Point {
+            &self.0
+        }
+
+        fn set_pos(&mut self, pos: Point) {
+            self.0 = pos;
+        }
+
+        fn next_step(&self, _field: &Field, _spider: &Spider, _rng: &mut dyn RngCore) -> Direction {
+            Direction::NONE
+        }
+    }
+
+    let field = Field::new(10, 4);
+    let spider = Spider::new(
+        Point::origin(),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(10, 4),
+    );
+    let game = Game::with_seed(
+        field,
+        spider,
+        vec![Box::new(StillEnemy(Point::new(1, 1)))],
+        0,
+    );
+
+    assert!(serde_json::to_string(&game).is_err());
+}
+
+#[test]
+fn load_rejects_invalid_polygon() {
+    assert!(serde_json::from_str::<Polygon>(
+        r#"[{"x": 0, "y": 0}, {"x": 10, "y": 0}, {"x": 10, "y": 10}, {"x": 0, "y": 10}]"#
+    )
+    .is_ok());
+    assert!(serde_json::from_str::<Polygon>(
+        r#"[{"x": 0, "y": 0}, {"x": 10, "y": 5}, {"x": 10, "y": 10}, {"x": 0, "y": 10}]"#
+    )
+    .is_err());
+}
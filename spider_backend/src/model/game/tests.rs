@@ -0,0 +1,998 @@
+use super::*;
+
+use rand::RngCore;
+
+use crate::model::score;
+use crate::model::snake::Snake;
+use crate::model::sparx::{Orientation, Sparx};
+use crate::model::speed::SUBSTEPS_PER_STEP;
+
+const WIDTH: i32 = 10;
+const HEIGHT: i32 = 4;
+
+// The spider starts in the middle of the upper edge and cuts the field in half when moving
+// down. The games of these tests hold the draw command, so the spider leaves the edges
+// whenever it is steered into the free polygon.
+fn get_game() -> Game {
+    get_game_with_spider_speed(Speed::normal())
+}
+
+fn get_game_with_spider_speed(speed: Speed) -> Game {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut spider = Spider::new(
+        Point::new(WIDTH / 2, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    spider.set_speed(speed);
+    let snake = Snake::new(Point::new(1, 1));
+
+    let mut game = Game::new(field, spider, vec![Box::new(snake)]);
+    game.handle_draw();
+    game.handle_draw();
+    game
+}
+
+fn snake_pos(game: &Game) -> &Point {
+    game.enemies()[0].pos()
+}
+
+fn run_ticks(game: &mut Game, ticks: usize) {
+    for _ in 0..ticks {
+        game.update_state();
+    }
+}
+
+#[test]
+fn game_not_complete_at_start() {
+    let mut game = get_game();
+    game.update_state();
+
+    assert_eq!(GameState::Ready, game.state());
+}
+
+#[test]
+fn game_claim_threshold_clamped() {
+    let mut game = get_game();
+
+    game.set_claim_threshold(150);
+    assert_eq!(100, game.claim_threshold());
+
+    game.set_claim_threshold(-1);
+    assert_eq!(0, game.claim_threshold());
+}
+
+#[test]
+fn game_level_complete_when_threshold_reached() {
+    let mut game = get_game();
+    game.set_claim_threshold(50);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+
+    assert_eq!(50, game.field().claimed_percentage());
+    assert_eq!(GameState::LevelComplete, game.state());
+}
+
+#[test]
+fn game_level_not_complete_below_threshold() {
+    let mut game = get_game();
+    game.set_claim_threshold(51);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+
+    assert_eq!(50, game.field().claimed_percentage());
+    assert_eq!(GameState::Running, game.state());
+}
+
+#[test]
+fn game_no_update_after_level_complete() {
+    let mut game = get_game();
+    game.set_claim_threshold(50);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+    assert_eq!(GameState::LevelComplete, game.state());
+
+    let spider_pos = *game.spider().pos();
+    game.handle_right();
+    run_ticks(&mut game, 2);
+
+    assert_eq!(&spider_pos, game.spider().pos());
+}
+
+#[test]
+fn game_state_transitions() {
+    use GameState::*;
+
+    assert!(Ready.can_transition_to(Running));
+    assert!(Running.can_transition_to(Paused));
+    assert!(Paused.can_transition_to(Running));
+    assert!(Running.can_transition_to(GameOver));
+    assert!(Running.can_transition_to(LevelComplete));
+
+    assert!(!Ready.can_transition_to(Paused));
+    assert!(!Paused.can_transition_to(GameOver));
+    assert!(!GameOver.can_transition_to(Running));
+    assert!(!LevelComplete.can_transition_to(Running));
+    assert!(Running.can_transition_to(LifeLost));
+    assert!(LifeLost.can_transition_to(Running));
+    assert!(!LifeLost.can_transition_to(Paused));
+}
+
+#[test]
+fn game_direction_starts_game() {
+    let mut game = get_game();
+    game.handle_pause();
+    assert_eq!(GameState::Ready, game.state());
+
+    game.handle_down();
+    assert_eq!(GameState::Running, game.state());
+    assert_eq!(Direction::DOWN, game.spider().get_dir());
+}
+
+#[test]
+fn game_spider_leaves_edges_only_while_drawing() {
+    let mut game = get_game();
+    game.handle_release_draw();
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2, 0), game.spider().pos());
+    assert!(!game.spider().has_path());
+
+    game.handle_draw();
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2, 1), game.spider().pos());
+    assert!(game.spider().has_path());
+}
+
+#[test]
+fn game_paused_ignores_updates_and_directions() {
+    let mut game = get_game();
+    game.handle_down();
+    game.handle_pause();
+    assert_eq!(GameState::Paused, game.state());
+
+    let spider_pos = *game.spider().pos();
+    let old_snake_pos = *snake_pos(&game);
+    game.handle_right();
+    run_ticks(&mut game, 2);
+
+    assert_eq!(&spider_pos, game.spider().pos());
+    assert_eq!(&old_snake_pos, snake_pos(&game));
+    assert_eq!(Direction::DOWN, game.spider().get_dir());
+
+    game.handle_pause();
+    assert_eq!(GameState::Running, game.state());
+}
+
+fn get_game_with_random_snake(snake_pos: Point) -> Game {
+    let field = Field::new(WIDTH, HEIGHT);
+    let spider = Spider::new(
+        Point::new(WIDTH / 2, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    let snake = Snake::with_aggression(snake_pos, 0.0);
+
+    let mut game = Game::new(field, spider, vec![Box::new(snake)]);
+    game.handle_draw();
+    game.handle_stop();
+    game
+}
+
+#[test]
+fn game_snake_confined_to_field() {
+    let mut game = get_game_with_random_snake(Point::new(1, 1));
+
+    for _ in 0..200 {
+        let old_pos = *snake_pos(&game);
+        game.update_state();
+        let new_pos = *snake_pos(&game);
+
+        assert!(game.field().is_free(&new_pos));
+        assert!(game.field().is_free_step(&old_pos, &new_pos));
+    }
+}
+
+#[test]
+fn game_snake_confined_after_cut() {
+    let mut game = get_game_with_random_snake(Point::new(1, 1));
+    game.set_claim_threshold(100);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+    assert_eq!(20, game.field().free_area());
+
+    for _ in 0..200 {
+        game.update_state();
+        assert!(game.field().is_free(snake_pos(&game)));
+    }
+}
+
+#[test]
+fn game_snake_outside_free_polygon_stays() {
+    let outside = Point::new(-3, -3);
+    let mut game = get_game_with_random_snake(outside);
+
+    run_ticks(&mut game, 10);
+    assert_eq!(&outside, snake_pos(&game));
+}
+
+#[derive(Debug)]
+struct StillEnemy {
+    pos: Point,
+}
+
+impl Enemy for StillEnemy {
+    fn pos(&self) -> &Point {
+        &self.pos
+    }
+
+    fn set_pos(&mut self, pos: Point) {
+        self.pos = pos;
+    }
+
+    fn next_step(&self, _field: &Field, _spider: &Spider, _rng: &mut dyn RngCore) -> Direction {
+        Direction::NONE
+    }
+}
+
+fn get_game_with_still_enemies(positions: &[Point]) -> Game {
+    let field = Field::new(WIDTH, HEIGHT);
+    let spider = Spider::new(
+        Point::new(WIDTH / 2, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    let enemies = positions
+        .iter()
+        .map(|pos| Box::new(StillEnemy { pos: *pos }) as Box<dyn Enemy>)
+        .collect();
+
+    let mut game = Game::new(field, spider, enemies);
+    game.handle_draw();
+    game.set_claim_threshold(100);
+    game
+}
+
+#[test]
+fn game_any_enemy_eats_spider() {
+    let mut game = get_game_with_still_enemies(&[Point::new(1, 1), Point::new(WIDTH / 2, 2)]);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::Running, game.state());
+
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+#[test]
+fn game_cut_keeps_side_with_more_enemies() {
+    let mut game =
+        get_game_with_still_enemies(&[Point::new(1, 1), Point::new(7, 1), Point::new(8, 3)]);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+
+    assert!(game.field().free_polygon().is_inside(&Point::new(7, 1)));
+    assert!(game.field().free_polygon().is_inside(&Point::new(8, 3)));
+    // The enemy on the claimed side stays in the game, but it is no longer active.
+    assert_eq!(3, game.enemies().len());
+    let active: Vec<Point> = game.active_enemies().map(|enemy| *enemy.pos()).collect();
+    assert_eq!(vec![Point::new(7, 1), Point::new(8, 3)], active);
+}
+
+#[test]
+fn game_enemy_in_claimed_area_inactive() {
+    // The enemy at (4, 3) ends up in the claimed area, the enemy on the right edge catches the
+    // spider.
+    let mut game = get_game_with_still_enemies(&[
+        Point::new(4, 3),
+        Point::new(8, 1),
+        Point::new(9, 3),
+        Point::new(WIDTH, 2),
+    ]);
+    game.set_lives(2);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+    game.handle_right();
+    run_ticks(&mut game, (WIDTH / 2) as usize);
+    game.handle_up();
+    run_ticks(&mut game, 2);
+    assert_eq!(GameState::LifeLost, game.state());
+
+    // The spider respawns on the corner that is the farthest away from the active enemies,
+    // right next to the inactive one.
+    assert_eq!(&Point::new(WIDTH / 2, HEIGHT), game.spider().pos());
+    assert_eq!(&Point::new(4, 3), game.enemies()[0].pos());
+}
+
+#[test]
+fn game_cut_keeps_larger_side_without_enemies() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let spider = Spider::new(
+        Point::new(3, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    let mut game = Game::new(field, spider, Vec::new());
+    game.handle_draw();
+    game.set_claim_threshold(100);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+
+    assert_eq!(28, game.field().free_area());
+}
+
+#[test]
+fn game_sparx_eats_spider_on_edge() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let spider = Spider::new(
+        Point::new(WIDTH / 2, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    let sparx = Sparx::new(Point::new(WIDTH / 2 - 2, 0), Orientation::Clockwise);
+    let mut game = Game::new(field, spider, vec![Box::new(sparx)]);
+    game.handle_draw();
+
+    game.handle_stop();
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::Running, game.state());
+
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+// An enemy that keeps walking in the same direction.
+#[derive(Debug)]
+struct WalkingEnemy {
+    pos: Point,
+    dir: Direction,
+    speed: Option<Speed>,
+}
+
+impl Enemy for WalkingEnemy {
+    fn pos(&self) -> &Point {
+        &self.pos
+    }
+
+    fn set_pos(&mut self, pos: Point) {
+        self.pos = pos;
+    }
+
+    fn next_step(&self, _field: &Field, _spider: &Spider, _rng: &mut dyn RngCore) -> Direction {
+        self.dir
+    }
+
+    fn speed_mut(&mut self) -> Option<&mut Speed> {
+        self.speed.as_mut()
+    }
+}
+
+fn get_game_with_walking_enemy(pos: Point, dir: Direction) -> Game {
+    get_game_with_walking_enemy_at_speed(pos, dir, Speed::normal())
+}
+
+fn get_game_with_walking_enemy_at_speed(pos: Point, dir: Direction, speed: Speed) -> Game {
+    let field = Field::new(WIDTH, HEIGHT);
+    let spider = Spider::new(
+        Point::new(WIDTH / 2, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    let enemy = WalkingEnemy {
+        pos,
+        dir,
+        speed: Some(speed),
+    };
+
+    let mut game = Game::new(field, spider, vec![Box::new(enemy)]);
+    game.handle_draw();
+    game.set_claim_threshold(100);
+    game
+}
+
+#[test]
+fn game_enemy_on_path_eats_spider() {
+    let mut game = get_game_with_walking_enemy(Point::new(WIDTH / 2 + 2, 1), Direction::LEFT);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::Running, game.state());
+
+    // The enemy steps on the path right behind the spider.
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+#[test]
+fn game_enemy_next_to_path_does_not_eat_spider() {
+    let mut game = get_game_with_walking_enemy(Point::new(WIDTH / 2 + 1, 1), Direction::DOWN);
+
+    game.handle_down();
+    run_ticks(&mut game, 2);
+    assert_eq!(GameState::Running, game.state());
+    assert!(game.spider().has_path());
+}
+
+#[test]
+fn game_enemy_swapping_places_eats_spider() {
+    let mut game = get_game_with_walking_enemy(Point::new(WIDTH / 2 + 1, 0), Direction::LEFT);
+
+    // The spider and the enemy swap places, their final positions differ.
+    game.handle_right();
+    run_ticks(&mut game, 1);
+
+    assert_eq!(&Point::new(WIDTH / 2, 0), game.enemies()[0].pos());
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+#[test]
+fn game_enemy_following_spider_does_not_eat_it() {
+    let mut game = get_game_with_walking_enemy(Point::new(WIDTH / 2 - 1, 0), Direction::RIGHT);
+
+    game.handle_right();
+    run_ticks(&mut game, 3);
+
+    assert_eq!(GameState::Running, game.state());
+}
+
+#[test]
+fn game_spider_walking_into_still_enemy_is_eaten() {
+    let mut game = get_game_with_still_enemies(&[Point::new(WIDTH / 2 + 1, 0)]);
+
+    game.handle_right();
+    run_ticks(&mut game, 1);
+
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+#[test]
+fn game_life_lost_respawns_spider() {
+    let mut game = get_game_with_walking_enemy(Point::new(WIDTH / 2 + 2, 1), Direction::LEFT);
+
+    game.handle_down();
+    run_ticks(&mut game, 2);
+
+    assert_eq!(GameState::LifeLost, game.state());
+    assert_eq!(DEFAULT_LIVES - 1, game.lives());
+    assert_eq!(INVULNERABLE_TICKS, game.invulnerable_ticks());
+    assert!(!game.spider().has_path());
+    assert_eq!(Direction::NONE, game.spider().get_dir());
+    assert!(game.field().free_polygon().is_on_edge(game.spider().pos()));
+
+    let vertices = game.field().free_polygon().path().points();
+    assert!(vertices.contains(game.spider().pos()));
+}
+
+#[test]
+fn game_life_lost_waits_for_input() {
+    let mut game = get_game_with_still_enemies(&[Point::new(WIDTH / 2, 1)]);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+
+    let spider_pos = *game.spider().pos();
+    run_ticks(&mut game, 2);
+    assert_eq!(&spider_pos, game.spider().pos());
+
+    game.handle_pause();
+    assert_eq!(GameState::LifeLost, game.state());
+
+    game.handle_left();
+    assert_eq!(GameState::Running, game.state());
+}
+
+#[test]
+fn game_invulnerable_after_life_lost() {
+    let mut game = get_game_with_still_enemies(&[Point::new(WIDTH / 2, 1), Point::new(1, HEIGHT)]);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+    assert_eq!(&Point::new(WIDTH, HEIGHT), game.spider().pos());
+
+    // Walk over the enemy on the lower edge while invulnerable.
+    game.handle_left();
+    run_ticks(&mut game, INVULNERABLE_TICKS as usize);
+    assert_eq!(&Point::new(0, HEIGHT), game.spider().pos());
+    assert_eq!(GameState::Running, game.state());
+    assert_eq!(0, game.invulnerable_ticks());
+
+    game.handle_right();
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+#[test]
+fn game_over_without_lives() {
+    let mut game = get_game_with_still_enemies(&[Point::new(WIDTH / 2, 1)]);
+    game.set_lives(1);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+
+    assert_eq!(GameState::GameOver, game.state());
+    assert_eq!(0, game.lives());
+}
+
+#[test]
+fn game_score_starts_at_zero() {
+    let game = get_game();
+    assert_eq!(&Score::new(), game.score());
+}
+
+#[test]
+fn game_score_for_cut() {
+    let mut game = get_game_with_still_enemies(&[Point::new(1, 1)]);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+
+    let mut expected = Score::new();
+    expected.add_claimed_area(20, WIDTH * HEIGHT);
+    assert_eq!(&expected, game.score());
+    assert_eq!(1, game.score().large_cuts());
+}
+
+#[test]
+fn game_score_level_complete_bonus() {
+    let mut game = get_game_with_still_enemies(&[Point::new(1, 1)]);
+    game.set_claim_threshold(40);
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+    assert_eq!(GameState::LevelComplete, game.state());
+
+    let mut expected = Score::new();
+    expected.add_claimed_area(20, WIDTH * HEIGHT);
+    let cut_points = expected.points();
+    assert_eq!(
+        cut_points + 10 * score::POINTS_PER_PERCENT_OVER_THRESHOLD,
+        game.score().points()
+    );
+}
+
+#[test]
+fn game_slow_draw_doubles_points_and_halves_speed() {
+    let mut game = get_game_with_still_enemies(&[Point::new(1, 1)]);
+    game.handle_toggle_draw_mode();
+    assert_eq!(DrawMode::Slow, game.draw_mode());
+
+    game.handle_down();
+    run_ticks(&mut game, HEIGHT as usize);
+    assert_eq!(0, game.score().points());
+    run_ticks(&mut game, HEIGHT as usize);
+
+    let mut expected = Score::new();
+    expected.add_claimed_area(20, WIDTH * HEIGHT);
+    assert_eq!(
+        expected.points() * score::SLOW_DRAW_MULTIPLIER,
+        game.score().points()
+    );
+}
+
+#[test]
+fn game_draw_mode_kept_while_drawing() {
+    let mut game = get_game();
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    game.drain_events().for_each(drop);
+
+    game.handle_toggle_draw_mode();
+    assert_eq!(DrawMode::Fast, game.draw_mode());
+    assert!(game.events().is_empty());
+}
+
+#[test]
+fn game_speed_level_clamped() {
+    let mut game = get_game();
+
+    game.handle_speed_up();
+    assert_eq!(&[GameEvent::SpeedChanged { level: 1 }], game.events());
+
+    for _ in 0..10 {
+        game.handle_speed_up();
+    }
+    assert_eq!(MAX_SPEED_LEVEL, game.speed_level());
+    for _ in 0..10 {
+        game.handle_slow_down();
+    }
+    assert_eq!(MIN_SPEED_LEVEL, game.speed_level());
+
+    // Only the changes are reported: up to the fastest level, then down to the slowest.
+    let changes = MAX_SPEED_LEVEL + (MAX_SPEED_LEVEL - MIN_SPEED_LEVEL);
+    assert_eq!(changes as usize, game.events().len());
+}
+
+#[test]
+fn game_session_commands_not_logged() {
+    let mut game = get_game();
+
+    for command in &[
+        RouterCommand::RESTART,
+        RouterCommand::QUIT,
+        RouterCommand::SAVE,
+        RouterCommand::LOAD,
+    ] {
+        game.handle_command(*command);
+    }
+
+    assert!(game.command_log().is_empty());
+    assert_eq!(
+        &[
+            GameEvent::RestartRequested,
+            GameEvent::QuitRequested,
+            GameEvent::SaveRequested,
+            GameEvent::LoadRequested,
+        ],
+        game.events()
+    );
+}
+
+fn get_seeded_game(seed: u64) -> Game {
+    let field = Field::new(WIDTH, HEIGHT);
+    let spider = Spider::new(
+        Point::new(WIDTH / 2, 0),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    );
+    let snake = Snake::with_aggression(Point::new(1, 1), 0.5);
+
+    let mut game = Game::with_seed(field, spider, vec![Box::new(snake)], seed);
+    game.handle_draw();
+    game.handle_stop();
+    game
+}
+
+fn snake_trajectory(game: &mut Game, ticks: usize) -> Vec<Point> {
+    (0..ticks)
+        .map(|_| {
+            game.update_state();
+            *snake_pos(game)
+        })
+        .collect()
+}
+
+#[test]
+fn game_seed() {
+    let game = get_seeded_game(42);
+    assert_eq!(42, game.seed());
+}
+
+#[test]
+fn game_same_seed_same_game() {
+    let mut game1 = get_seeded_game(42);
+    let mut game2 = get_seeded_game(42);
+
+    assert_eq!(
+        snake_trajectory(&mut game1, 50),
+        snake_trajectory(&mut game2, 50)
+    );
+}
+
+#[test]
+fn game_different_seed_different_game() {
+    let mut game1 = get_seeded_game(1);
+    let mut game2 = get_seeded_game(2);
+
+    assert_ne!(
+        snake_trajectory(&mut game1, 50),
+        snake_trajectory(&mut game2, 50)
+    );
+}
+
+#[test]
+fn game_fast_spider() {
+    let mut game = get_game_with_spider_speed(Speed::new(2 * SUBSTEPS_PER_STEP));
+    game.set_claim_threshold(100);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2, 2), game.spider().pos());
+
+    // The cut is made as soon as the spider reaches the lower edge.
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2, HEIGHT), game.spider().pos());
+    assert_eq!(50, game.field().claimed_percentage());
+}
+
+#[test]
+fn game_slow_spider() {
+    let mut game = get_game_with_spider_speed(Speed::new(SUBSTEPS_PER_STEP / 2));
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2, 0), game.spider().pos());
+
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2, 1), game.spider().pos());
+}
+
+#[test]
+fn game_fast_enemy() {
+    let enemy_start = Point::new(WIDTH / 2 + 4, 1);
+
+    // At normal speed the enemy doesn't reach the path in two ticks.
+    let mut game = get_game_with_walking_enemy(enemy_start, Direction::LEFT);
+    game.handle_down();
+    run_ticks(&mut game, 2);
+    assert_eq!(GameState::Running, game.state());
+
+    // At twice the speed it steps on the path in the second tick.
+    let mut game = get_game_with_walking_enemy_at_speed(
+        enemy_start,
+        Direction::LEFT,
+        Speed::new(2 * SUBSTEPS_PER_STEP),
+    );
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(&Point::new(WIDTH / 2 + 2, 1), game.enemies()[0].pos());
+    assert_eq!(GameState::Running, game.state());
+
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+}
+
+#[test]
+fn game_events_for_cut() {
+    let mut game = get_game_with_still_enemies(&[Point::new(1, 1)]);
+    game.set_claim_threshold(40);
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    assert_eq!(
+        vec![GameEvent::PathStarted {
+            start: Point::new(WIDTH / 2, 0)
+        }],
+        game.drain_events().collect::<Vec<_>>()
+    );
+    assert!(game.events().is_empty());
+
+    run_ticks(&mut game, HEIGHT as usize - 1);
+    let events: Vec<_> = game.drain_events().collect();
+    assert_eq!(2, events.len());
+    match &events[0] {
+        GameEvent::AreaClaimed {
+            polygon,
+            area,
+            points,
+        } => {
+            assert_eq!(20, *area);
+            assert_eq!(20, polygon.area());
+            assert!(polygon.is_inside(&Point::new(8, 1)));
+            assert_eq!(
+                game.score().points() - 10 * score::POINTS_PER_PERCENT_OVER_THRESHOLD,
+                *points
+            );
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    assert_eq!(
+        GameEvent::LevelComplete {
+            bonus: 10 * score::POINTS_PER_PERCENT_OVER_THRESHOLD
+        },
+        events[1]
+    );
+}
+
+#[test]
+fn game_events_for_pause() {
+    let mut game = get_game();
+    game.handle_down();
+    game.handle_pause();
+    game.handle_pause();
+
+    assert_eq!(
+        vec![GameEvent::Paused, GameEvent::Resumed],
+        game.drain_events().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn game_events_for_life_lost() {
+    let mut game = get_game_with_walking_enemy(Point::new(WIDTH / 2 + 2, 1), Direction::LEFT);
+
+    game.handle_down();
+    run_ticks(&mut game, 2);
+
+    assert_eq!(
+        &[
+            GameEvent::PathStarted {
+                start: Point::new(WIDTH / 2, 0)
+            },
+            GameEvent::SpiderEaten,
+            GameEvent::PathAbandoned,
+            GameEvent::LifeLost {
+                lives_left: DEFAULT_LIVES - 1
+            },
+        ],
+        game.events()
+    );
+}
+
+#[test]
+fn game_events_for_game_over() {
+    let mut game = get_game_with_still_enemies(&[Point::new(WIDTH / 2 + 1, 0)]);
+    game.set_lives(1);
+
+    game.handle_right();
+    run_ticks(&mut game, 1);
+
+    assert_eq!(
+        &[GameEvent::SpiderEaten, GameEvent::GameOver],
+        game.events()
+    );
+}
+
+#[test]
+fn game_without_fuse_by_default() {
+    let mut game = get_game_with_still_enemies(&[]);
+    assert_eq!(None, game.fuse());
+
+    game.handle_down();
+    run_ticks(&mut game, 1);
+    game.handle_stop();
+    run_ticks(&mut game, 20);
+
+    assert_eq!(GameState::Running, game.state());
+}
+
+#[test]
+fn game_fuse_catches_idle_spider() {
+    let mut game = get_game_with_still_enemies(&[]);
+    game.set_fuse(Some(Fuse::new(1)));
+
+    game.handle_down();
+    run_ticks(&mut game, 2);
+    game.handle_stop();
+
+    // The fuse is lit after the delay and burns the two units of the path.
+    run_ticks(&mut game, 2);
+    assert!(game.fuse().unwrap().is_lit());
+    assert!(game.events().contains(&GameEvent::FuseLit));
+    assert_eq!(GameState::Running, game.state());
+
+    run_ticks(&mut game, 1);
+    assert_eq!(GameState::LifeLost, game.state());
+    assert!(!game.fuse().unwrap().is_lit());
+}
+
+#[test]
+fn game_fuse_retreats() {
+    let mut game = get_game_with_still_enemies(&[]);
+    game.set_fuse(Some(Fuse::new(0)));
+
+    game.handle_down();
+    run_ticks(&mut game, 2);
+    game.handle_stop();
+    run_ticks(&mut game, 1);
+    assert_eq!(1, game.fuse().unwrap().burnt());
+
+    game.handle_left();
+    run_ticks(&mut game, 1);
+    assert!(!game.fuse().unwrap().is_lit());
+    assert_eq!(GameState::Running, game.state());
+}
+
+#[test]
+fn game_queued_commands_applied_on_update() {
+    let mut game = get_game();
+    game.queue_command(RouterCommand::DOWN);
+    assert_eq!(GameState::Ready, game.state());
+    assert!(game.command_log().is_empty());
+
+    game.update_state();
+    assert_eq!(GameState::Running, game.state());
+    assert_eq!(&Point::new(WIDTH / 2, 1), game.spider().pos());
+    assert_eq!(0, game.command_log()[0].tick);
+}
+
+#[test]
+fn game_queued_directions_one_per_tick() {
+    let mut game = get_game();
+    game.queue_command(RouterCommand::DOWN);
+    game.queue_command(RouterCommand::RIGHT);
+
+    // The tap on right is not overwritten by down, it follows on the next tick.
+    game.update_state();
+    assert_eq!(&Point::new(WIDTH / 2, 1), game.spider().pos());
+    assert_eq!(1, game.queued_commands().len());
+
+    game.update_state();
+    assert_eq!(&Point::new(WIDTH / 2 + 1, 1), game.spider().pos());
+    assert!(game.queued_commands().is_empty());
+}
+
+#[test]
+fn game_queued_commands_keep_order() {
+    let mut game = get_game();
+    game.queue_command(RouterCommand::RELEASE_DRAW);
+    game.queue_command(RouterCommand::PAUSE);
+    game.queue_command(RouterCommand::DOWN);
+    game.queue_command(RouterCommand::PAUSE);
+
+    // The first pause has no effect before the game started, the second one pauses it.
+    game.update_state();
+    assert!(!game.spider().is_draw_held());
+    assert_eq!(GameState::Paused, game.state());
+    assert_eq!(&Point::new(WIDTH / 2, 0), game.spider().pos());
+}
+
+#[test]
+fn game_queued_commands_skip_waiting_directions() {
+    let mut game = get_game();
+    game.queue_command(RouterCommand::DOWN);
+    game.queue_command(RouterCommand::RIGHT);
+    game.queue_command(RouterCommand::RELEASE_DRAW);
+
+    game.update_state();
+    assert!(!game.spider().is_draw_held());
+    assert_eq!(Some(&RouterCommand::RIGHT), game.queued_commands().front());
+    assert_eq!(1, game.queued_commands().len());
+}
+
+#[test]
+fn game_queued_repeated_directions_collapse() {
+    let mut game = get_game_with_still_enemies(&[Point::new(1, 1)]);
+    game.queue_command(RouterCommand::RIGHT);
+    game.update_state();
+
+    // A held key repeats, then the player turns.
+    for _ in 0..25 {
+        game.queue_command(RouterCommand::RIGHT);
+    }
+    game.queue_command(RouterCommand::DOWN);
+    assert_eq!(1, game.queued_commands().len());
+
+    game.update_state();
+    assert_eq!(Direction::DOWN, game.spider().get_dir());
+    assert!(game.queued_commands().is_empty());
+}
+
+#[test]
+fn game_queued_directions_limited() {
+    let mut game = get_game();
+    for command in &[
+        RouterCommand::DOWN,
+        RouterCommand::LEFT,
+        RouterCommand::UP,
+        RouterCommand::PAUSE,
+        RouterCommand::RIGHT,
+    ] {
+        game.queue_command(*command);
+    }
+
+    // The newest directions are kept.
+    let queued: Vec<_> = game.queued_commands().iter().copied().collect();
+    assert_eq!(
+        vec![
+            RouterCommand::UP,
+            RouterCommand::PAUSE,
+            RouterCommand::RIGHT
+        ],
+        queued
+    );
+    assert_eq!(
+        MAX_QUEUED_DIRECTIONS,
+        queued
+            .iter()
+            .filter(|command| command.is_direction())
+            .count()
+    );
+}
@@ -1,6 +1,13 @@
-pub mod game;
+pub mod config;
+pub mod enemy;
+pub mod event;
 pub mod field;
+pub mod fuse;
+pub mod game;
 pub mod point;
 pub mod rectilinear;
+pub mod score;
 pub mod snake;
+pub mod speed;
+pub mod sparx;
 pub mod spider;
@@ -1,6 +1,10 @@
 use std::borrow::Borrow;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Point {
     pub x: i32,
     pub y: i32,
@@ -31,6 +35,7 @@ impl Point {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Direction {
     UP,
     DOWN,
@@ -49,6 +54,16 @@ impl Direction {
             Direction::NONE => Point::new(0, 0),
         }
     }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::UP => Direction::DOWN,
+            Direction::DOWN => Direction::UP,
+            Direction::LEFT => Direction::RIGHT,
+            Direction::RIGHT => Direction::LEFT,
+            Direction::NONE => Direction::NONE,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -43,3 +43,22 @@ fn dir_to_point() {
     let res: Vec<Point> = directions.iter().map(Direction::to_point).collect();
     assert_eq!(exp, res);
 }
+
+#[test]
+fn dir_opposite() {
+    let directions = [
+        Direction::UP,
+        Direction::DOWN,
+        Direction::LEFT,
+        Direction::RIGHT,
+        Direction::NONE,
+    ];
+
+    for dir in &directions {
+        assert_eq!(
+            Point::origin(),
+            dir.to_point().add(dir.opposite().to_point())
+        );
+        assert_eq!(*dir, dir.opposite().opposite());
+    }
+}
@@ -37,6 +37,12 @@ impl<PointT: Borrow<Point>> Line<PointT> {
         self.end.borrow()
     }
 
+    /// The number of unit steps from the start to the end of the line.
+    pub fn length(&self) -> i32 {
+        let diff = self.end().subtract(self.start());
+        diff.x.abs() + diff.y.abs()
+    }
+
     pub fn vertical(&self) -> bool {
         self.start().x == self.end().x
     }
@@ -110,6 +116,16 @@ impl<PointT: Borrow<Point>> Line<PointT> {
         }
     }
 
+    /// Returns the point of the line segment that is closest to `point`.
+    pub fn closest_point(&self, point: &Point) -> Point {
+        let start = self.start();
+        let end = self.end();
+
+        let x = point.x.clamp(start.x.min(end.x), start.x.max(end.x));
+        let y = point.y.clamp(start.y.min(end.y), start.y.max(end.y));
+        Point::new(x, y)
+    }
+
     pub fn intersects<PointT2>(&self, other: &Line<PointT2>) -> bool
     where
         PointT2: Borrow<Point>,
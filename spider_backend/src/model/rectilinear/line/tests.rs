@@ -315,3 +315,15 @@ fn line_intersections() {
 
     assert_eq!(expected, res);
 }
+
+#[test]
+fn line_closest_point() {
+    let start = Point::new(1, 2);
+    let end = Point::new(5, 2);
+    let line = Line::from_points(&start, &end).unwrap();
+
+    assert_eq!(Point::new(3, 2), line.closest_point(&Point::new(3, 2)));
+    assert_eq!(Point::new(3, 2), line.closest_point(&Point::new(3, -4)));
+    assert_eq!(Point::new(1, 2), line.closest_point(&Point::new(-1, 3)));
+    assert_eq!(Point::new(5, 2), line.closest_point(&Point::new(9, 9)));
+}
@@ -1,5 +1,8 @@
 use std::borrow::Borrow;
 
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 use super::{Line, LineIntersection};
 use crate::iter::skip_last::SkipLastIterator;
 use crate::model::point::Point;
@@ -57,6 +60,30 @@ impl Path {
             .map(|(p1, p2)| Line::from_points(p1, p2).unwrap())
     }
 
+    /// The number of unit steps along the path from its first to its last point.
+    pub fn length(&self) -> i32 {
+        self.line_iter().map(|line| line.length()).sum()
+    }
+
+    /// The point `distance` unit steps along the path from its first point, or `None` if the path
+    /// is empty. Distances beyond the ends of the path are clamped to them.
+    pub fn point_at(&self, distance: i32) -> Option<Point> {
+        let mut remaining = distance.max(0);
+        for line in self.line_iter() {
+            let length = line.length();
+            if remaining <= length {
+                let step = line.direction().to_point();
+                return Some(Point::new(
+                    line.start().x + remaining * step.x,
+                    line.start().y + remaining * step.y,
+                ));
+            }
+            remaining -= length;
+        }
+
+        self.last().copied()
+    }
+
     pub fn add(&mut self, point: Point) -> Result<(), &'static str> {
         if let Some(last_point) = self.points_.last() {
             if *last_point == point {
@@ -140,5 +167,22 @@ impl Path {
     }
 }
 
+// Paths are stored as their points and checked for rectilinearity when they are loaded.
+#[cfg(feature = "serde")]
+impl Serialize for Path {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.points_.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Path, D::Error> {
+        let points = Vec::<Point>::deserialize(deserializer)?;
+        Path::with_points(points.iter())
+            .ok_or_else(|| de::Error::custom("the points of the path are not rectilinear"))
+    }
+}
+
 #[cfg(test)]
 mod tests;
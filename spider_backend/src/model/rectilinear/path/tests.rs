@@ -214,3 +214,27 @@ fn path_no_insertion_point_not_collinear() {
     assert_eq!(expected, res);
 }
 
+
+#[test]
+fn path_length() {
+    let points = [Point::new(0, 0), Point::new(20, 0), Point::new(20, 10)];
+    let path = Path::with_points(points.iter()).unwrap();
+
+    assert_eq!(30, path.length());
+    assert_eq!(0, Path::with_start(Point::new(1, 1)).length());
+    assert_eq!(0, Path::new().length());
+}
+
+#[test]
+fn path_point_at() {
+    let points = [Point::new(0, 0), Point::new(20, 0), Point::new(20, 10)];
+    let path = Path::with_points(points.iter()).unwrap();
+
+    assert_eq!(Some(Point::new(0, 0)), path.point_at(0));
+    assert_eq!(Some(Point::new(5, 0)), path.point_at(5));
+    assert_eq!(Some(Point::new(20, 0)), path.point_at(20));
+    assert_eq!(Some(Point::new(20, 3)), path.point_at(23));
+    assert_eq!(Some(Point::new(20, 10)), path.point_at(40));
+    assert_eq!(Some(Point::new(0, 0)), path.point_at(-1));
+    assert_eq!(None, Path::new().point_at(0));
+}
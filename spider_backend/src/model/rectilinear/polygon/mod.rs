@@ -4,13 +4,15 @@ use std::collections::VecDeque;
 use std::iter;
 
 use itertools::Itertools;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use super::{Line, LineIntersection, Path};
 use crate::iter::skip_last::SkipLastIterator;
 use crate::model::point::{Direction, Point};
 
 /// A rectilinear polygon.
-#[derive(Debug, Eq)]
+#[derive(Clone, Debug, Eq)]
 pub struct Polygon {
     path_: Path,
 }
@@ -109,6 +111,21 @@ impl Polygon {
         self.insertion_point(point).is_some()
     }
 
+    /// Returns the point on the edges of the polygon that is closest to `point`, or `None` if the
+    /// polygon is empty.
+    pub fn closest_edge_point(&self, point: &Point) -> Option<Point> {
+        if self.path().points().is_empty() {
+            return None;
+        }
+
+        self.line_iter()
+            .map(|line| line.closest_point(point))
+            .min_by_key(|closest| {
+                let diff = closest.subtract(point);
+                diff.x.abs() + diff.y.abs()
+            })
+    }
+
     pub fn is_inside(&self, point: &Point) -> bool {
         // Non-zero rule.
         // First we need to return false if the point is on an edge. We use
@@ -399,9 +416,11 @@ impl Polygon {
         debug_assert!(path.points().len() == 2);
 
         let path_end = path.points().last().unwrap();
+        // The edge ending at the insertion point, which wraps around for the first vertex.
+        let vertex_count = self.path().points().len();
         let mut left_or_right = self
             .line_iter()
-            .nth(path_start_insertion_point - 1)
+            .nth((path_start_insertion_point + vertex_count - 1) % vertex_count)
             .unwrap()
             .point_on_side(path_end);
 
@@ -426,7 +445,7 @@ impl Polygon {
     }
 
     // TODO: This could be cached.
-    fn is_clockwise(&self) -> bool {
+    pub fn is_clockwise(&self) -> bool {
         // Find the top left vertex, it is part of the convex hull.
         // See https://en.wikipedia.org/wiki/Curve_orientation#Orientation_of_a_simple_polygon.
         let cmp = |p1: &&Point, p2: &&Point| -> Ordering { p1.x.cmp(&p2.x).then(p1.y.cmp(&p2.y)) };
@@ -500,6 +519,23 @@ fn is_eq_backward(vertices1: &[Point], vertices2: &[Point], offset: usize) -> bo
     vertices1.iter().eq(other_iter)
 }
 
+// Polygons are stored as their path and checked with `Polygon::with_path` when they are loaded.
+#[cfg(feature = "serde")]
+impl Serialize for Polygon {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.path_.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Polygon {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Polygon, D::Error> {
+        let path = Path::deserialize(deserializer)?;
+        Polygon::with_path(path)
+            .map_err(|err| de::Error::custom(format!("invalid polygon: {:?}", err)))
+    }
+}
+
 #[cfg(test)]
 mod tests;
 
@@ -699,6 +699,22 @@ fn polygon_cut_path_line_outside_but_no_points() {
     assert_eq!(None, poly.cut(&cutting_path));
 }
 
+#[test]
+fn polygon_cut_path_along_edge_from_first_vertex() {
+    let points = [
+        Point::new(0, 0),
+        Point::new(10, 0),
+        Point::new(10, 10),
+        Point::new(0, 10),
+    ];
+    let poly_path = Path::with_points(points.iter()).unwrap();
+    let poly = Polygon::with_path(poly_path).unwrap();
+
+    let cutting_path = Path::with_points([Point::new(0, 0), Point::new(0, 1)].iter()).unwrap();
+
+    assert_eq!(None, poly.cut(&cutting_path));
+}
+
 #[test]
 fn polygon_cut_path_line_outside_but_no_points_multiple_lines() {
     let points = [
@@ -804,3 +820,55 @@ fn polygon_cut_path_0_vertex_path() {
 
     assert_eq!(None, poly.cut(&cutting_path));
 }
+
+#[test]
+fn polygon_closest_edge_point() {
+    let points = [
+        Point::new(0, 0),
+        Point::new(10, 0),
+        Point::new(10, 4),
+        Point::new(0, 4),
+    ];
+    let path = Path::with_points(points.iter()).unwrap();
+    let poly = Polygon::with_path(path).unwrap();
+
+    assert_eq!(
+        Some(Point::new(2, 0)),
+        poly.closest_edge_point(&Point::new(2, 1))
+    );
+    assert_eq!(
+        Some(Point::new(10, 3)),
+        poly.closest_edge_point(&Point::new(9, 3))
+    );
+    assert_eq!(
+        Some(Point::new(0, 4)),
+        poly.closest_edge_point(&Point::new(-3, 7))
+    );
+    assert_eq!(
+        Some(Point::new(5, 4)),
+        poly.closest_edge_point(&Point::new(5, 4))
+    );
+}
+
+#[test]
+fn polygon_closest_edge_point_empty() {
+    let poly = Polygon::with_path(Path::new()).unwrap();
+    assert_eq!(None, poly.closest_edge_point(&Point::new(1, 1)));
+}
+
+#[test]
+fn polygon_is_clockwise() {
+    let points = [
+        Point::new(0, 0),
+        Point::new(10, 0),
+        Point::new(10, 4),
+        Point::new(0, 4),
+    ];
+    let path = Path::with_points(points.iter()).unwrap();
+    let poly = Polygon::with_path(path).unwrap();
+    assert!(poly.is_clockwise());
+
+    let path = Path::with_points(points.iter().rev()).unwrap();
+    let poly = Polygon::with_path(path).unwrap();
+    assert!(!poly.is_clockwise());
+}
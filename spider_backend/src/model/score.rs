@@ -0,0 +1,81 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Points awarded for every unit of claimed area.
+pub const POINTS_PER_AREA: u32 = 10;
+
+/// A single cut that claims at least this percentage of the field is a large cut.
+pub const LARGE_CUT_PERCENTAGE: i32 = 10;
+
+/// Large cuts are awarded this many times the normal points.
+pub const LARGE_CUT_MULTIPLIER: u32 = 2;
+
+/// Cuts drawn in slow draw mode are awarded this many times the normal points, on top of the large
+/// cut bonus.
+pub const SLOW_DRAW_MULTIPLIER: u32 = 2;
+
+/// Points awarded for every percent claimed over the threshold when the level is completed.
+pub const POINTS_PER_PERCENT_OVER_THRESHOLD: u32 = 1000;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Score {
+    points_: u32,
+    large_cuts_: u32,
+}
+
+impl Score {
+    pub fn new() -> Score {
+        Score::default()
+    }
+
+    pub fn points(&self) -> u32 {
+        self.points_
+    }
+
+    /// The number of cuts that were awarded the large cut bonus.
+    pub fn large_cuts(&self) -> u32 {
+        self.large_cuts_
+    }
+
+    /// Awards points for a cut that claimed `area` of a field with an original area of
+    /// `total_area` and returns the points awarded.
+    pub fn add_claimed_area(&mut self, area: i32, total_area: i32) -> u32 {
+        let area = area.max(0);
+        let mut points = area as u32 * POINTS_PER_AREA;
+
+        if total_area > 0 && area * 100 / total_area >= LARGE_CUT_PERCENTAGE {
+            points *= LARGE_CUT_MULTIPLIER;
+            self.large_cuts_ += 1;
+        }
+
+        self.points_ += points;
+        points
+    }
+
+    /// Awards points like `add_claimed_area` for a cut that was drawn in slow draw mode.
+    pub fn add_slow_claimed_area(&mut self, area: i32, total_area: i32) -> u32 {
+        let points = self.add_claimed_area(area, total_area);
+        let bonus = points * (SLOW_DRAW_MULTIPLIER - 1);
+
+        self.points_ += bonus;
+        points + bonus
+    }
+
+    /// Awards the bonus for completing a level with `claimed_percentage` of the field claimed
+    /// when `threshold` percent was needed and returns the points awarded.
+    pub fn add_level_complete_bonus(&mut self, claimed_percentage: i32, threshold: i32) -> u32 {
+        let over_threshold = (claimed_percentage - threshold).max(0) as u32;
+        let points = over_threshold * POINTS_PER_PERCENT_OVER_THRESHOLD;
+
+        self.points_ += points;
+        points
+    }
+
+    pub fn reset(&mut self) {
+        *self = Score::default();
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+
+#[test]
+fn score_new_is_zero() {
+    let score = Score::new();
+
+    assert_eq!(0, score.points());
+    assert_eq!(0, score.large_cuts());
+}
+
+#[test]
+fn score_small_cut() {
+    let mut score = Score::new();
+
+    let points = score.add_claimed_area(5, 100);
+
+    assert_eq!(5 * POINTS_PER_AREA, points);
+    assert_eq!(points, score.points());
+    assert_eq!(0, score.large_cuts());
+}
+
+#[test]
+fn score_large_cut_bonus() {
+    let mut score = Score::new();
+
+    let points = score.add_claimed_area(LARGE_CUT_PERCENTAGE, 100);
+
+    assert_eq!(
+        LARGE_CUT_PERCENTAGE as u32 * POINTS_PER_AREA * LARGE_CUT_MULTIPLIER,
+        points
+    );
+    assert_eq!(1, score.large_cuts());
+}
+
+#[test]
+fn score_adds_up() {
+    let mut score = Score::new();
+
+    let first = score.add_claimed_area(1, 100);
+    let second = score.add_claimed_area(50, 100);
+
+    assert_eq!(first + second, score.points());
+}
+
+#[test]
+fn score_level_complete_bonus() {
+    let mut score = Score::new();
+
+    assert_eq!(0, score.add_level_complete_bonus(75, 75));
+    assert_eq!(0, score.add_level_complete_bonus(70, 75));
+    assert_eq!(
+        5 * POINTS_PER_PERCENT_OVER_THRESHOLD,
+        score.add_level_complete_bonus(80, 75)
+    );
+    assert_eq!(5 * POINTS_PER_PERCENT_OVER_THRESHOLD, score.points());
+}
+
+#[test]
+fn score_reset() {
+    let mut score = Score::new();
+    score.add_claimed_area(50, 100);
+
+    score.reset();
+
+    assert_eq!(Score::new(), score);
+}
+
+#[test]
+fn score_slow_draw_bonus() {
+    let mut score = Score::new();
+
+    let points = score.add_slow_claimed_area(LARGE_CUT_PERCENTAGE, 100);
+
+    assert_eq!(
+        LARGE_CUT_PERCENTAGE as u32 * POINTS_PER_AREA * LARGE_CUT_MULTIPLIER * SLOW_DRAW_MULTIPLIER,
+        points
+    );
+    assert_eq!(points, score.points());
+    assert_eq!(1, score.large_cuts());
+}
@@ -1,31 +1,170 @@
+use std::collections::{HashSet, VecDeque};
+
 use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+use super::enemy::{Enemy, EnemyKind, EnemyState};
 use super::field::Field;
 use super::point::{Direction, Point};
+use super::rectilinear::Path;
+use super::speed::Speed;
 use super::spider::Spider;
 
-#[derive(Debug)]
+/// The probability of the snake taking the optimal step towards the spider, unless set otherwise
+/// with `Snake::set_aggression`.
+pub const DEFAULT_AGGRESSION: f64 = 0.8;
+
+const STEPS: [Direction; 4] = [
+    Direction::UP,
+    Direction::LEFT,
+    Direction::DOWN,
+    Direction::RIGHT,
+];
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Snake {
     pos_: Point,
+    aggression_: f64,
+    speed_: Speed,
 }
 
 impl Snake {
     pub fn new(pos: Point) -> Snake {
-        Snake { pos_: pos }
+        Snake::with_aggression(pos, DEFAULT_AGGRESSION)
+    }
+
+    pub fn with_aggression(pos: Point, aggression: f64) -> Snake {
+        let mut snake = Snake {
+            pos_: pos,
+            aggression_: DEFAULT_AGGRESSION,
+            speed_: Speed::normal(),
+        };
+        snake.set_aggression(aggression);
+        snake
+    }
+
+    pub fn aggression(&self) -> f64 {
+        self.aggression_
+    }
+
+    /// Sets the probability of taking the optimal step. The value is clamped to the range [0, 1],
+    /// NaN is ignored.
+    pub fn set_aggression(&mut self, aggression: f64) {
+        if !aggression.is_nan() {
+            self.aggression_ = aggression.clamp(0.0, 1.0);
+        }
+    }
+
+    pub fn set_speed(&mut self, speed: Speed) {
+        self.speed_ = speed;
     }
 
-    pub fn next_step(&self, field: &Field, spider: &Spider) -> Direction {
-        // TODO: Implement it.
-        let mut rng = rand::thread_rng();
-        let directions = [Direction::UP, Direction::LEFT, Direction::DOWN, Direction::RIGHT, Direction::NONE];
-        *directions.choose(&mut rng).unwrap()
+    fn chase_step(&self, field: &Field, spider: &Spider) -> Option<Direction> {
+        let mut targets = HashSet::new();
+        targets.insert(*spider.pos());
+        if let Some(path) = spider.path() {
+            targets.extend(unit_points(path));
+        }
+
+        first_step_towards(field, self.pos(), &targets)
+    }
+
+    fn random_step<R: Rng + ?Sized>(&self, field: &Field, rng: &mut R) -> Direction {
+        let mut directions: Vec<Direction> = STEPS
+            .iter()
+            .copied()
+            .filter(|dir| field.is_free_step(self.pos(), &self.pos().add(dir.to_point())))
+            .collect();
+        directions.push(Direction::NONE);
+
+        *directions.choose(rng).unwrap()
     }
+}
 
-    pub fn pos(&self) -> &Point {
+impl Enemy for Snake {
+    fn pos(&self) -> &Point {
         &self.pos_
     }
 
-    pub fn set_pos(&mut self, point: Point) {
-        self.pos_ = point;
+    fn set_pos(&mut self, pos: Point) {
+        self.pos_ = pos;
+    }
+
+    fn kind(&self) -> Option<EnemyKind> {
+        Some(EnemyKind::Snake)
+    }
+
+    /// The snake chases the spider, or its path if it has one, along a shortest route inside the
+    /// free polygon of the field. With a probability of one minus the aggression, or if there is
+    /// no route, it takes a random step that stays inside the free polygon instead.
+    fn next_step(&self, field: &Field, spider: &Spider, rng: &mut dyn RngCore) -> Direction {
+        if rng.gen_bool(self.aggression_) {
+            if let Some(dir) = self.chase_step(field, spider) {
+                return dir;
+            }
+        }
+
+        self.random_step(field, rng)
+    }
+
+    fn speed(&self) -> Option<&Speed> {
+        Some(&self.speed_)
+    }
+
+    fn speed_mut(&mut self) -> Option<&mut Speed> {
+        Some(&mut self.speed_)
+    }
+
+    fn state(&self) -> Option<EnemyState> {
+        Some(EnemyState::Snake(self.clone()))
+    }
+}
+
+// Breadth first search on the integer points of the free polygon. Returns the first step of a
+// shortest route from `start` to the nearest target, or `None` if no target can be reached.
+fn first_step_towards(field: &Field, start: &Point, targets: &HashSet<Point>) -> Option<Direction> {
+    if targets.contains(start) {
+        return Some(Direction::NONE);
     }
+
+    let mut visited = HashSet::new();
+    visited.insert(*start);
+
+    // Each entry also holds the first step that was taken from `start` to get there.
+    let mut queue = VecDeque::new();
+    queue.push_back((*start, Direction::NONE));
+
+    while let Some((point, first_step)) = queue.pop_front() {
+        for dir in &STEPS {
+            let next = point.add(dir.to_point());
+            if visited.contains(&next) || !field.is_free_step(&point, &next) {
+                continue;
+            }
+
+            let first_step = if point == *start { *dir } else { first_step };
+            if targets.contains(&next) {
+                return Some(first_step);
+            }
+
+            visited.insert(next);
+            queue.push_back((next, first_step));
+        }
+    }
+
+    None
 }
+
+fn unit_points(path: &Path) -> impl Iterator<Item = Point> + '_ {
+    path.line_iter().flat_map(|line| {
+        let start = *line.start();
+        let step = line.direction().to_point();
+        let len = (line.end().x - start.x).abs() + (line.end().y - start.y).abs();
+        (0..=len).map(move |i| Point::new(start.x + i * step.x, start.y + i * step.y))
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,123 @@
+use super::*;
+
+use rand::SeedableRng;
+
+use crate::model::game::GameRng;
+
+fn get_spider(pos: Point) -> Spider {
+    Spider::new(pos, Direction::NONE, Point::origin(), Point::new(10, 10))
+}
+
+fn get_rng() -> GameRng {
+    GameRng::seed_from_u64(0)
+}
+
+fn claim_strip(field: &mut Field, points: &[Point]) {
+    let path = Path::with_points(points.iter()).unwrap();
+    let (poly1, poly2) = field.free_polygon().cut(&path).unwrap();
+    if poly1.area() > poly2.area() {
+        field.cut(poly1, poly2);
+    } else {
+        field.cut(poly2, poly1);
+    }
+}
+
+#[test]
+fn snake_aggression_clamped() {
+    let mut snake = Snake::new(Point::origin());
+
+    snake.set_aggression(2.0);
+    assert_eq!(1.0, snake.aggression());
+
+    snake.set_aggression(-1.0);
+    assert_eq!(0.0, snake.aggression());
+}
+
+#[test]
+fn snake_aggression_nan_ignored() {
+    let mut snake = Snake::with_aggression(Point::origin(), f64::NAN);
+    assert_eq!(DEFAULT_AGGRESSION, snake.aggression());
+
+    snake.set_aggression(0.5);
+    snake.set_aggression(f64::NAN);
+    assert_eq!(0.5, snake.aggression());
+}
+
+#[test]
+fn snake_chases_spider() {
+    let field = Field::new(10, 10);
+    let snake = Snake::with_aggression(Point::new(2, 5), 1.0);
+
+    assert_eq!(
+        Direction::RIGHT,
+        snake.next_step(&field, &get_spider(Point::new(7, 5)), &mut get_rng())
+    );
+    assert_eq!(
+        Direction::LEFT,
+        snake.next_step(&field, &get_spider(Point::new(0, 5)), &mut get_rng())
+    );
+    assert_eq!(
+        Direction::UP,
+        snake.next_step(&field, &get_spider(Point::new(2, 0)), &mut get_rng())
+    );
+    assert_eq!(
+        Direction::DOWN,
+        snake.next_step(&field, &get_spider(Point::new(2, 10)), &mut get_rng())
+    );
+}
+
+#[test]
+fn snake_chases_spider_path() {
+    let field = Field::new(10, 10);
+    let snake = Snake::with_aggression(Point::new(5, 3), 1.0);
+
+    // The spider is far to the right but its path is right below the snake.
+    let mut spider = get_spider(Point::new(0, 5));
+    spider.set_dir(Direction::RIGHT);
+    spider.set_draw_held(true);
+    for _ in 0..9 {
+        spider.step(&field);
+    }
+
+    assert_eq!(
+        Direction::DOWN,
+        snake.next_step(&field, &spider, &mut get_rng())
+    );
+}
+
+#[test]
+fn snake_chases_around_claimed_area() {
+    // Claim a wall between the snake and the spider that can only be passed below.
+    let mut field = Field::new(10, 10);
+    claim_strip(
+        &mut field,
+        &[
+            Point::new(4, 0),
+            Point::new(4, 8),
+            Point::new(5, 8),
+            Point::new(5, 0),
+        ],
+    );
+
+    let snake = Snake::with_aggression(Point::new(3, 1), 1.0);
+    let spider = get_spider(Point::new(7, 1));
+
+    assert_eq!(
+        Direction::DOWN,
+        snake.next_step(&field, &spider, &mut get_rng())
+    );
+}
+
+#[test]
+fn snake_random_step_stays_free() {
+    let field = Field::new(10, 10);
+    let snake = Snake::with_aggression(Point::origin(), 0.0);
+    let spider = get_spider(Point::new(5, 5));
+
+    let mut rng = get_rng();
+    for _ in 0..50 {
+        let dir = snake.next_step(&field, &spider, &mut rng);
+        let new_pos = snake.pos().add(dir.to_point());
+        assert!(field.is_free_step(snake.pos(), &new_pos));
+    }
+}
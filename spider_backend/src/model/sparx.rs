@@ -0,0 +1,138 @@
+use rand::RngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::enemy::{Enemy, EnemyKind, EnemyState};
+use super::field::Field;
+use super::point::{Direction, Point};
+use super::rectilinear::{Line, Polygon};
+use super::speed::Speed;
+use super::spider::Spider;
+
+/// The direction in which a sparx walks around the free polygon.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Orientation {
+    Clockwise,
+    AntiClockwise,
+}
+
+/// An enemy that patrols the edges of the free polygon.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sparx {
+    pos_: Point,
+    orientation_: Orientation,
+    speed_: Speed,
+}
+
+impl Sparx {
+    pub fn new(pos: Point, orientation: Orientation) -> Sparx {
+        Sparx {
+            pos_: pos,
+            orientation_: orientation,
+            speed_: Speed::normal(),
+        }
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation_
+    }
+
+    pub fn set_speed(&mut self, speed: Speed) {
+        self.speed_ = speed;
+    }
+
+    // Moves the sparx to the closest point on the edges of the free polygon if it is not on an
+    // edge.
+    fn reattach(&mut self, field: &Field) {
+        let polygon = field.free_polygon();
+        if polygon.is_on_edge(&self.pos_) {
+            return;
+        }
+
+        if let Some(point) = polygon.closest_edge_point(&self.pos_) {
+            self.pos_ = point;
+        }
+    }
+
+    // Whether the sparx walks in the order of the polygon's vertices.
+    fn forward(&self, polygon: &Polygon) -> bool {
+        let clockwise = self.orientation_ == Orientation::Clockwise;
+        clockwise == polygon.is_clockwise()
+    }
+}
+
+impl Enemy for Sparx {
+    fn pos(&self) -> &Point {
+        &self.pos_
+    }
+
+    fn set_pos(&mut self, pos: Point) {
+        self.pos_ = pos;
+    }
+
+    fn kind(&self) -> Option<EnemyKind> {
+        Some(EnemyKind::Sparx)
+    }
+
+    /// The next step along the edges of the free polygon, or `Direction::NONE` if the sparx is not
+    /// on an edge.
+    fn next_step(&self, field: &Field, _spider: &Spider, _rng: &mut dyn RngCore) -> Direction {
+        let polygon = field.free_polygon();
+        let vertices = polygon.path().points();
+        let insertion_idx = match polygon.insertion_point(&self.pos_) {
+            Some(idx) => idx,
+            None => return Direction::NONE,
+        };
+
+        // The sparx is on the edge that ends at the vertex with index `insertion_idx`. If it is on
+        // a vertex, that edge ends at the sparx.
+        let len = vertices.len();
+        let target = if self.forward(polygon) {
+            let end = &vertices[insertion_idx];
+            if *end == self.pos_ {
+                &vertices[(insertion_idx + 1) % len]
+            } else {
+                end
+            }
+        } else {
+            let start = &vertices[(insertion_idx + len - 1) % len];
+            if *start == self.pos_ {
+                &vertices[(insertion_idx + len - 2) % len]
+            } else {
+                start
+            }
+        };
+
+        Line::from_points(&self.pos_, target)
+            .map(|line| line.direction())
+            .unwrap_or(Direction::NONE)
+    }
+
+    fn update(&mut self, field: &Field, spider: &Spider, rng: &mut dyn RngCore) {
+        self.reattach(field);
+
+        let dir = self.next_step(field, spider, rng);
+        self.pos_ = self.pos_.add(dir.to_point());
+    }
+
+    fn field_changed(&mut self, field: &Field) {
+        self.reattach(field);
+    }
+
+    fn speed(&self) -> Option<&Speed> {
+        Some(&self.speed_)
+    }
+
+    fn speed_mut(&mut self) -> Option<&mut Speed> {
+        Some(&mut self.speed_)
+    }
+
+    fn state(&self) -> Option<EnemyState> {
+        Some(EnemyState::Sparx(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use super::*;
+
+use rand::SeedableRng;
+
+use crate::model::game::GameRng;
+use crate::test_util::claim_right_half;
+
+const WIDTH: i32 = 10;
+const HEIGHT: i32 = 4;
+
+fn get_spider() -> Spider {
+    Spider::new(
+        Point::origin(),
+        Direction::NONE,
+        Point::origin(),
+        Point::new(WIDTH, HEIGHT),
+    )
+}
+
+fn walk(sparx: &mut Sparx, field: &Field, steps: usize) -> Vec<Point> {
+    let spider = get_spider();
+    let mut rng = GameRng::seed_from_u64(0);
+    (0..steps)
+        .map(|_| {
+            sparx.update(field, &spider, &mut rng);
+            *sparx.pos()
+        })
+        .collect()
+}
+
+#[test]
+fn sparx_walks_clockwise() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut sparx = Sparx::new(Point::new(WIDTH - 1, 0), Orientation::Clockwise);
+
+    let expected = vec![Point::new(WIDTH, 0), Point::new(WIDTH, 1)];
+    assert_eq!(expected, walk(&mut sparx, &field, 2));
+}
+
+#[test]
+fn sparx_walks_anti_clockwise() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut sparx = Sparx::new(Point::new(1, 0), Orientation::AntiClockwise);
+
+    let expected = vec![Point::new(0, 0), Point::new(0, 1)];
+    assert_eq!(expected, walk(&mut sparx, &field, 2));
+}
+
+#[test]
+fn sparx_walks_around_the_polygon() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let perimeter = 2 * (WIDTH + HEIGHT) as usize;
+
+    for orientation in &[Orientation::Clockwise, Orientation::AntiClockwise] {
+        let start = Point::new(3, HEIGHT);
+        let mut sparx = Sparx::new(start, *orientation);
+
+        let positions = walk(&mut sparx, &field, perimeter);
+        assert!(positions
+            .iter()
+            .all(|pos| field.free_polygon().is_on_edge(pos)));
+        assert_eq!(Some(&start), positions.last());
+    }
+}
+
+#[test]
+fn sparx_reattaches_to_edge() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut sparx = Sparx::new(Point::new(5, 1), Orientation::Clockwise);
+
+    let positions = walk(&mut sparx, &field, 1);
+    assert_eq!(vec![Point::new(6, 0)], positions);
+}
+
+#[test]
+fn sparx_reattaches_after_cut() {
+    let mut field = Field::new(WIDTH, HEIGHT);
+    let mut sparx = Sparx::new(Point::new(WIDTH - 2, HEIGHT), Orientation::Clockwise);
+
+    // The sparx is on the lower edge of the claimed half.
+    claim_right_half(&mut field);
+
+    sparx.field_changed(&field);
+    assert_eq!(&Point::new(WIDTH / 2, HEIGHT), sparx.pos());
+    assert!(field.free_polygon().is_on_edge(sparx.pos()));
+
+    let positions = walk(&mut sparx, &field, 1);
+    assert_eq!(vec![Point::new(WIDTH / 2 - 1, HEIGHT)], positions);
+}
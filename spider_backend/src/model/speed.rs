@@ -0,0 +1,55 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The number of sub-steps a unit step is divided into.
+pub const SUBSTEPS_PER_STEP: u32 = 4;
+
+/// How fast an entity moves, in sub-steps per tick. At `SUBSTEPS_PER_STEP` sub-steps per tick the
+/// entity takes one unit step every tick, at half of that it takes one every other tick and at
+/// twice that it takes two every tick.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Speed {
+    substeps_per_tick_: u32,
+    // The sub-steps accumulated towards the next unit step.
+    progress_: u32,
+}
+
+impl Speed {
+    pub fn new(substeps_per_tick: u32) -> Speed {
+        Speed {
+            substeps_per_tick_: substeps_per_tick,
+            progress_: 0,
+        }
+    }
+
+    /// One unit step per tick.
+    pub fn normal() -> Speed {
+        Speed::new(SUBSTEPS_PER_STEP)
+    }
+
+    pub fn substeps_per_tick(&self) -> u32 {
+        self.substeps_per_tick_
+    }
+
+    pub fn set_substeps_per_tick(&mut self, substeps_per_tick: u32) {
+        self.substeps_per_tick_ = substeps_per_tick;
+    }
+
+    /// Advances by one tick and returns the number of unit steps that are due in it.
+    pub fn tick(&mut self) -> u32 {
+        self.progress_ += self.substeps_per_tick_;
+        let steps = self.progress_ / SUBSTEPS_PER_STEP;
+        self.progress_ %= SUBSTEPS_PER_STEP;
+        steps
+    }
+}
+
+impl Default for Speed {
+    fn default() -> Speed {
+        Speed::normal()
+    }
+}
+
+#[cfg(test)]
+mod tests;
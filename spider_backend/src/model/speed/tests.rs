@@ -0,0 +1,42 @@
+use super::*;
+
+fn steps(speed: &mut Speed, ticks: usize) -> Vec<u32> {
+    (0..ticks).map(|_| speed.tick()).collect()
+}
+
+#[test]
+fn speed_normal() {
+    assert_eq!(vec![1, 1, 1, 1], steps(&mut Speed::normal(), 4));
+}
+
+#[test]
+fn speed_slow() {
+    let mut speed = Speed::new(SUBSTEPS_PER_STEP / 2);
+    assert_eq!(vec![0, 1, 0, 1], steps(&mut speed, 4));
+
+    let mut speed = Speed::new(3);
+    assert_eq!(vec![0, 1, 1, 1, 0, 1, 1, 1], steps(&mut speed, 8));
+}
+
+#[test]
+fn speed_fast() {
+    let mut speed = Speed::new(SUBSTEPS_PER_STEP * 2);
+    assert_eq!(vec![2, 2, 2], steps(&mut speed, 3));
+
+    let mut speed = Speed::new(6);
+    assert_eq!(vec![1, 2, 1, 2], steps(&mut speed, 4));
+}
+
+#[test]
+fn speed_stopped() {
+    assert_eq!(vec![0, 0, 0], steps(&mut Speed::new(0), 3));
+}
+
+#[test]
+fn speed_change_keeps_progress() {
+    let mut speed = Speed::new(2);
+    assert_eq!(0, speed.tick());
+
+    speed.set_substeps_per_tick(SUBSTEPS_PER_STEP);
+    assert_eq!(1, speed.tick());
+}
@@ -1,11 +1,31 @@
+use super::field::Field;
 use super::point::{Direction, Point};
 use super::rectilinear;
+use super::speed::Speed;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Whether the spider is walking on the border of the free polygon or drawing a path through it.
+///
+/// * `OnBorder`: the spider may only move along the edges of the free polygon. It starts drawing
+///   when it steps into the free polygon, which it only does while the draw command is held.
+/// * `Drawing`: the spider extends `path` with every step until it reaches the border again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SpiderState {
+    OnBorder,
+    Drawing { path: rectilinear::Path },
+}
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Spider {
     dir_: Direction,
     pos_: Point,
-    path_: Option<rectilinear::Path>,
+    state_: SpiderState,
+    draw_held_: bool,
+    speed_: Speed,
 
     upper_left_: Point,
     lower_right_: Point,
@@ -16,7 +36,9 @@ impl Spider {
         Spider {
             dir_: dir,
             pos_: pos,
-            path_: None,
+            state_: SpiderState::OnBorder,
+            draw_held_: false,
+            speed_: Speed::normal(),
             upper_left_: upper_left,
             lower_right_: lower_right,
         }
@@ -26,12 +48,32 @@ impl Spider {
         &self.pos_
     }
 
+    /// Moves the spider without extending its path.
+    pub fn set_pos(&mut self, pos: Point) {
+        self.pos_ = pos;
+    }
+
+    pub fn state(&self) -> &SpiderState {
+        &self.state_
+    }
+
+    pub fn is_drawing(&self) -> bool {
+        matches!(self.state_, SpiderState::Drawing { .. })
+    }
+
+    /// Starts a path at the spider's position, regardless of the draw command.
     pub fn start_path(&mut self) {
-        self.path_ = Some(rectilinear::Path::with_start(*self.pos()));
+        self.state_ = SpiderState::Drawing {
+            path: rectilinear::Path::with_start(*self.pos()),
+        };
     }
 
+    /// Puts the spider back on the border, returning the path it was drawing.
     pub fn stop_path(&mut self) -> Option<rectilinear::Path> {
-        self.path_.take()
+        match std::mem::replace(&mut self.state_, SpiderState::OnBorder) {
+            SpiderState::OnBorder => None,
+            SpiderState::Drawing { path } => Some(path),
+        }
     }
 
     pub fn has_path(&self) -> bool {
@@ -39,24 +81,66 @@ impl Spider {
     }
 
     pub fn path(&self) -> Option<&rectilinear::Path> {
-        self.path_.as_ref()
+        match &self.state_ {
+            SpiderState::OnBorder => None,
+            SpiderState::Drawing { path } => Some(path),
+        }
     }
 
-    pub fn update(&mut self) {
-        let pos = self.pos();
-        let dir_point = self.dir_.to_point();
-        let new_pos = pos.add(dir_point);
+    pub fn is_draw_held(&self) -> bool {
+        self.draw_held_
+    }
 
-        if !self.pos_in_bounds(&new_pos) {
-            return;
-        }
+    /// Sets whether the draw command is held, which allows the spider to leave the border.
+    pub fn set_draw_held(&mut self, held: bool) {
+        self.draw_held_ = held;
+    }
 
-        self.pos_ = new_pos;
+    pub fn speed(&self) -> &Speed {
+        &self.speed_
+    }
 
-        if let Some(ref mut path) = self.path_ {
-            path.add(new_pos).expect(
-                "Should not happen because the available directions guarantee rectilinearity.",
-            );
+    pub fn speed_mut(&mut self) -> &mut Speed {
+        &mut self.speed_
+    }
+
+    pub fn set_speed(&mut self, speed: Speed) {
+        self.speed_ = speed;
+    }
+
+    /// Takes a single unit step in the spider's direction, regardless of its speed, which the game
+    /// ticks itself. A step that is not allowed in the spider's state is not taken. Returns the
+    /// path if the step finished it on the border of the free polygon.
+    pub fn step(&mut self, field: &Field) -> Option<rectilinear::Path> {
+        let pos = *self.pos();
+        let new_pos = pos.add(self.dir_.to_point());
+
+        if new_pos == pos || !self.pos_in_bounds(&new_pos) {
+            return None;
+        }
+
+        match self.state_ {
+            SpiderState::OnBorder => {
+                if field.is_border_step(&pos, &new_pos) {
+                    self.pos_ = new_pos;
+                } else if self.draw_held_ && field.is_free_step(&pos, &new_pos) {
+                    self.start_path();
+                    self.extend_path(new_pos);
+                }
+                None
+            }
+            SpiderState::Drawing { .. } => {
+                if !field.is_free_step(&pos, &new_pos) {
+                    return None;
+                }
+
+                self.extend_path(new_pos);
+                if field.free_polygon().is_inside(&new_pos) {
+                    None
+                } else {
+                    self.stop_path()
+                }
+            }
         }
     }
 
@@ -68,6 +152,16 @@ impl Spider {
         self.dir_ = dir;
     }
 
+    fn extend_path(&mut self, new_pos: Point) {
+        self.pos_ = new_pos;
+
+        if let SpiderState::Drawing { path } = &mut self.state_ {
+            path.add(new_pos).expect(
+                "Should not happen because the available directions guarantee rectilinearity.",
+            );
+        }
+    }
+
     fn pos_in_bounds(&self, new_pos: &Point) -> bool {
         self.upper_left_.x <= new_pos.x
             && new_pos.x <= self.lower_right_.x
@@ -1,15 +1,25 @@
 use super::*;
 
-fn get_spider() -> Spider {
-    let null_point = Point::new(0, 0);
+use crate::test_util::claim_right_half;
+
+const WIDTH: i32 = 10;
+const HEIGHT: i32 = 4;
+
+fn get_spider(pos: Point) -> Spider {
     let upper_left = Point::new(-100, -100);
     let lower_right = Point::new(100, 100);
-    Spider::new(null_point, Direction::NONE, upper_left, lower_right)
+    Spider::new(pos, Direction::NONE, upper_left, lower_right)
+}
+
+fn get_cut_field() -> Field {
+    let mut field = Field::new(WIDTH, HEIGHT);
+    claim_right_half(&mut field);
+    field
 }
 
 #[test]
 fn spider_set_get_dir() {
-    let mut spider = get_spider();
+    let mut spider = get_spider(Point::origin());
 
     spider.set_dir(Direction::UP);
     assert_eq!(Direction::UP, spider.get_dir());
@@ -19,65 +29,116 @@ fn spider_set_get_dir() {
 }
 
 #[test]
-fn spider_update_right_no_path() {
-    for dir in &[
-        Direction::UP,
-        Direction::DOWN,
-        Direction::LEFT,
-        Direction::RIGHT,
-        Direction::NONE,
-    ] {
-        let mut spider = get_spider();
-        let old_pos = *spider.pos();
-
-        spider.set_dir(*dir);
-        spider.stop_path();
-        spider.update();
-
-        let expected = old_pos.add(dir.to_point());
-        let new_pos = spider.pos();
-        assert_eq!(&expected, new_pos);
-        assert_eq!(None, spider.path());
-    }
+fn spider_walks_along_border() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut spider = get_spider(Point::new(WIDTH / 2, 0));
+
+    spider.set_dir(Direction::RIGHT);
+    assert_eq!(None, spider.step(&field));
+    assert_eq!(&Point::new(WIDTH / 2 + 1, 0), spider.pos());
+    assert_eq!(&SpiderState::OnBorder, spider.state());
+
+    // Corners are not turned automatically.
+    let mut spider = get_spider(Point::new(WIDTH, 0));
+    spider.set_dir(Direction::RIGHT);
+    spider.step(&field);
+    assert_eq!(&Point::new(WIDTH, 0), spider.pos());
+
+    spider.set_dir(Direction::DOWN);
+    spider.step(&field);
+    assert_eq!(&Point::new(WIDTH, 1), spider.pos());
+}
+
+#[test]
+fn spider_stays_on_border_without_draw() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut spider = get_spider(Point::new(WIDTH / 2, 0));
+
+    spider.set_dir(Direction::DOWN);
+    spider.step(&field);
+
+    assert_eq!(&Point::new(WIDTH / 2, 0), spider.pos());
+    assert!(!spider.is_drawing());
+}
+
+#[test]
+fn spider_draws_path_while_draw_held() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut spider = get_spider(Point::new(WIDTH / 2, 0));
+    spider.set_draw_held(true);
+    spider.set_dir(Direction::DOWN);
+
+    assert_eq!(None, spider.step(&field));
+    assert!(spider.is_drawing());
+    let expected_path =
+        rectilinear::Path::with_points([Point::new(WIDTH / 2, 0), Point::new(WIDTH / 2, 1)].iter())
+            .unwrap();
+    assert_eq!(Some(&expected_path), spider.path());
+
+    // The path continues when the draw command is released.
+    spider.set_draw_held(false);
+    spider.step(&field);
+    spider.step(&field);
+    assert!(spider.is_drawing());
+
+    let path = spider.step(&field);
+    let expected_path = rectilinear::Path::with_points(
+        [Point::new(WIDTH / 2, 0), Point::new(WIDTH / 2, HEIGHT)].iter(),
+    )
+    .unwrap();
+    assert_eq!(Some(expected_path), path);
+    assert_eq!(&SpiderState::OnBorder, spider.state());
+    assert_eq!(&Point::new(WIDTH / 2, HEIGHT), spider.pos());
 }
 
 #[test]
-fn spider_update_right_with_path() {
-    for dir in &[
-        Direction::UP,
-        Direction::DOWN,
-        Direction::LEFT,
-        Direction::RIGHT,
-        Direction::NONE,
-    ] {
-        let mut spider = get_spider();
-        let old_pos = *spider.pos();
-
-        spider.set_dir(*dir);
-        spider.start_path();
-        spider.update();
-
-        let expected = old_pos.add(dir.to_point());
-        let new_pos = spider.pos();
-
-        let expected_path = rectilinear::Path::with_points([old_pos, expected].iter()).unwrap();
-        assert_eq!(&expected, new_pos);
-        assert_eq!(Some(&expected_path), spider.path());
-
-        spider.stop_path();
-        assert_eq!(None, spider.path());
+fn spider_does_not_enter_claimed_area() {
+    let field = get_cut_field();
+
+    // The upper edge of the claimed half is no longer part of the border.
+    let mut spider = get_spider(Point::new(WIDTH / 2, 0));
+    spider.set_draw_held(true);
+    spider.set_dir(Direction::RIGHT);
+    spider.step(&field);
+    assert_eq!(&Point::new(WIDTH / 2, 0), spider.pos());
+
+    // Nor can a path be drawn into it.
+    let mut spider = get_spider(Point::new(1, 0));
+    spider.set_draw_held(true);
+    spider.set_dir(Direction::DOWN);
+    spider.step(&field);
+    spider.set_dir(Direction::RIGHT);
+    for _ in 0..WIDTH {
+        spider.step(&field);
     }
+    assert_eq!(&Point::new(WIDTH / 2, 1), spider.pos());
+    assert!(!spider.is_drawing());
+}
+
+#[test]
+fn spider_stop_path() {
+    let field = Field::new(WIDTH, HEIGHT);
+    let mut spider = get_spider(Point::new(WIDTH / 2, 0));
+    assert_eq!(None, spider.stop_path());
+
+    spider.set_draw_held(true);
+    spider.set_dir(Direction::DOWN);
+    spider.step(&field);
+
+    assert!(spider.stop_path().is_some());
+    assert_eq!(&SpiderState::OnBorder, spider.state());
+    assert_eq!(None, spider.path());
 }
 
 #[test]
-fn spider_update_out_of_bounds() {
-    let null_point = Point::new(0, 0);
+fn spider_step_out_of_bounds() {
+    let field = Field::new(WIDTH, HEIGHT);
     let upper_left = Point::new(0, 0);
-    let lower_right = Point::new(100, 100);
-    let mut spider = Spider::new(null_point, Direction::NONE, upper_left, lower_right);
+    let lower_right = Point::new(WIDTH, HEIGHT);
+    let mut spider = Spider::new(Point::origin(), Direction::NONE, upper_left, lower_right);
+    spider.set_draw_held(true);
 
     spider.set_dir(Direction::LEFT);
-    spider.update();
-    let new_pos = spider.pos();
-    assert_eq!(&null_point, new_pos);
+    spider.step(&field);
+    assert_eq!(&Point::origin(), spider.pos());
 }
@@ -0,0 +1,385 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::model::config::{EnemyConfig, GameConfig};
+use crate::model::game::Game;
+use crate::model::point::Point;
+use crate::model::sparx::Orientation;
+use crate::model::speed::SUBSTEPS_PER_STEP;
+use crate::router::{RouterCommand, TimedCommand};
+
+/// A recording of a game: the configuration it was created from, the commands that were applied
+/// to it and the number of ticks it ran for. Since all random decisions come from the seeded game
+/// RNG, replaying the commands on a game built from the same configuration reproduces the game.
+///
+/// A replay is stored as plain text, one entry per line:
+///
+/// ```text
+/// seed 42
+/// field 50 20
+/// spider 0 0 4
+/// claim_threshold 75
+/// lives 3
+/// fuse 6
+/// snake 10 10 0.8 4
+/// sparx 50 20 clockwise 2
+/// ticks 120
+/// command 0 RIGHT
+/// command 17 DOWN
+/// ```
+///
+/// The last value of the `spider`, `snake` and `sparx` entries is the speed in sub-steps per tick
+/// and may be left out for the normal speed. The `fuse` entry is optional and turns the fuse rule
+/// on. Empty lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    config_: GameConfig,
+    ticks_: u64,
+    commands_: Vec<TimedCommand>,
+}
+
+impl Replay {
+    pub fn new(config: GameConfig, ticks: u64, commands: Vec<TimedCommand>) -> Replay {
+        Replay {
+            config_: config,
+            ticks_: ticks,
+            commands_: commands,
+        }
+    }
+
+    /// Records the commands applied to `game` so far. `config` has to be the configuration the
+    /// game was built from.
+    pub fn record(config: &GameConfig, game: &Game) -> Replay {
+        Replay::new(config.clone(), game.tick(), game.command_log().to_vec())
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config_
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.ticks_
+    }
+
+    pub fn commands(&self) -> &[TimedCommand] {
+        &self.commands_
+    }
+
+    /// Plays the whole replay on a new game and returns the game in its final state.
+    pub fn play(&self) -> Game {
+        let mut game = self.config_.build();
+        let mut player = ReplayPlayer::new(self.clone());
+        while player.step(&mut game) {}
+        game
+    }
+
+    pub fn parse(text: &str) -> Result<Replay, ParseError> {
+        let mut replay = Replay::new(
+            GameConfig {
+                enemies: Vec::new(),
+                ..GameConfig::default()
+            },
+            0,
+            Vec::new(),
+        );
+        let mut ticks = None;
+        // The lines of the entries with a position, which can only be checked against the field
+        // once all entries are known.
+        let mut positions = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let pos = replay
+                .parse_entry(line, &mut ticks)
+                .map_err(|message| ParseError::new(index + 1, message))?;
+            if let Some(pos) = pos {
+                positions.push((index + 1, pos));
+            }
+        }
+
+        let config = &replay.config_;
+        for (line, pos) in positions {
+            if pos.x < 0 || pos.y < 0 || pos.x > config.width || pos.y > config.height {
+                return Err(ParseError::new(
+                    line,
+                    format!(
+                        "position {} {} is outside the {}x{} field",
+                        pos.x, pos.y, config.width, config.height
+                    ),
+                ));
+            }
+        }
+
+        // Without an explicit tick count the replay ends right after the last command.
+        replay.ticks_ =
+            ticks.unwrap_or_else(|| replay.commands_.last().map_or(0, |last| last.tick));
+        Ok(replay)
+    }
+
+    /// Parses a single entry into the replay. Returns the position given by the entry, if any.
+    fn parse_entry(
+        &mut self,
+        line: &str,
+        ticks: &mut Option<u64>,
+    ) -> Result<Option<Point>, String> {
+        let mut words = line.split_whitespace();
+        let key = words.next().unwrap();
+        let args: Vec<&str> = words.collect();
+        let config = &mut self.config_;
+
+        match key {
+            "seed" => {
+                expect_args(key, &args, 1)?;
+                config.seed = parse_arg(args[0])?;
+            }
+            "field" => {
+                expect_args(key, &args, 2)?;
+                config.width = parse_arg(args[0])?;
+                config.height = parse_arg(args[1])?;
+                if config.width <= 0 || config.height <= 0 {
+                    return Err(format!(
+                        "invalid field size {}x{}",
+                        config.width, config.height
+                    ));
+                }
+            }
+            "spider" => {
+                expect_args_between(key, &args, 2, 3)?;
+                config.spider_pos = Point::new(parse_arg(args[0])?, parse_arg(args[1])?);
+                config.spider_speed = parse_speed(args.get(2))?;
+                return Ok(Some(config.spider_pos));
+            }
+            "claim_threshold" => {
+                expect_args(key, &args, 1)?;
+                config.claim_threshold = parse_arg(args[0])?;
+            }
+            "lives" => {
+                expect_args(key, &args, 1)?;
+                config.lives = parse_arg(args[0])?;
+            }
+            "fuse" => {
+                expect_args(key, &args, 1)?;
+                config.fuse_delay = Some(parse_arg(args[0])?);
+            }
+            "snake" => {
+                expect_args_between(key, &args, 3, 4)?;
+                let pos = Point::new(parse_arg(args[0])?, parse_arg(args[1])?);
+                let aggression: f64 = parse_arg(args[2])?;
+                if !(0.0..=1.0).contains(&aggression) {
+                    return Err(format!("aggression {} is not in [0, 1]", args[2]));
+                }
+                config.enemies.push(EnemyConfig::Snake {
+                    pos,
+                    aggression,
+                    speed: parse_speed(args.get(3))?,
+                });
+                return Ok(Some(pos));
+            }
+            "sparx" => {
+                expect_args_between(key, &args, 3, 4)?;
+                let pos = Point::new(parse_arg(args[0])?, parse_arg(args[1])?);
+                config.enemies.push(EnemyConfig::Sparx {
+                    pos,
+                    orientation: parse_orientation(args[2])?,
+                    speed: parse_speed(args.get(3))?,
+                });
+                return Ok(Some(pos));
+            }
+            "ticks" => {
+                expect_args(key, &args, 1)?;
+                *ticks = Some(parse_arg(args[0])?);
+            }
+            "command" => {
+                expect_args(key, &args, 2)?;
+                let tick = parse_arg(args[0])?;
+                let command = RouterCommand::from_name(args[1])
+                    .ok_or_else(|| format!("unknown command '{}'", args[1]))?;
+                if self.commands_.last().is_some_and(|last| last.tick > tick) {
+                    return Err(String::from("commands are not ordered by tick"));
+                }
+                self.commands_.push(TimedCommand { tick, command });
+            }
+            _ => return Err(format!("unknown entry '{}'", key)),
+        }
+
+        Ok(None)
+    }
+}
+
+impl fmt::Display for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let config = &self.config_;
+        writeln!(f, "seed {}", config.seed)?;
+        writeln!(f, "field {} {}", config.width, config.height)?;
+        writeln!(
+            f,
+            "spider {} {} {}",
+            config.spider_pos.x, config.spider_pos.y, config.spider_speed
+        )?;
+        writeln!(f, "claim_threshold {}", config.claim_threshold)?;
+        writeln!(f, "lives {}", config.lives)?;
+        if let Some(delay) = config.fuse_delay {
+            writeln!(f, "fuse {}", delay)?;
+        }
+        for enemy in &config.enemies {
+            match enemy {
+                EnemyConfig::Snake {
+                    pos,
+                    aggression,
+                    speed,
+                } => writeln!(f, "snake {} {} {} {}", pos.x, pos.y, aggression, speed)?,
+                EnemyConfig::Sparx {
+                    pos,
+                    orientation,
+                    speed,
+                } => writeln!(
+                    f,
+                    "sparx {} {} {} {}",
+                    pos.x,
+                    pos.y,
+                    orientation_name(*orientation),
+                    speed
+                )?,
+            }
+        }
+        writeln!(f, "ticks {}", self.ticks_)?;
+        for timed in &self.commands_ {
+            writeln!(f, "command {} {}", timed.tick, timed.command.name())?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies the commands of a replay to a game one tick at a time, so that a frontend can show the
+/// replay at its own pace.
+#[derive(Debug)]
+pub struct ReplayPlayer {
+    replay_: Replay,
+    next_command_: usize,
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> ReplayPlayer {
+        ReplayPlayer {
+            replay_: replay,
+            next_command_: 0,
+        }
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay_
+    }
+
+    /// Takes the commands recorded for the ticks up to `tick` that haven't been taken yet, for
+    /// callers that apply the commands themselves.
+    pub fn take_due_commands(&mut self, tick: u64) -> &[TimedCommand] {
+        let first = self.next_command_;
+        let commands = &self.replay_.commands_;
+        while let Some(timed) = commands.get(self.next_command_) {
+            if timed.tick > tick {
+                break;
+            }
+            self.next_command_ += 1;
+        }
+        &commands[first..self.next_command_]
+    }
+
+    /// Applies the commands recorded for the current tick of `game` and updates the game. Returns
+    /// `false` without updating the game once all recorded ticks have been played.
+    pub fn step(&mut self, game: &mut Game) -> bool {
+        for timed in self.take_due_commands(game.tick()) {
+            game.handle_command(timed.command);
+        }
+
+        if game.tick() >= self.replay_.ticks_ {
+            return false;
+        }
+
+        game.update_state();
+        true
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line_: usize,
+    message_: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, message: String) -> ParseError {
+        ParseError {
+            line_: line,
+            message_: message,
+        }
+    }
+
+    /// The line the error occurred on, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line_
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message_
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line_, self.message_)
+    }
+}
+
+impl Error for ParseError {}
+
+fn orientation_name(orientation: Orientation) -> &'static str {
+    match orientation {
+        Orientation::Clockwise => "clockwise",
+        Orientation::AntiClockwise => "anticlockwise",
+    }
+}
+
+fn parse_orientation(name: &str) -> Result<Orientation, String> {
+    match name {
+        "clockwise" => Ok(Orientation::Clockwise),
+        "anticlockwise" => Ok(Orientation::AntiClockwise),
+        _ => Err(format!("unknown orientation '{}'", name)),
+    }
+}
+
+fn expect_args(key: &str, args: &[&str], count: usize) -> Result<(), String> {
+    expect_args_between(key, args, count, count)
+}
+
+fn expect_args_between(key: &str, args: &[&str], min: usize, max: usize) -> Result<(), String> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else {
+            format!("{} to {}", min, max)
+        };
+        return Err(format!(
+            "'{}' expects {} values but got {}",
+            key,
+            expected,
+            args.len()
+        ));
+    }
+    Ok(())
+}
+
+// Parses an optional speed, which defaults to the normal speed.
+fn parse_speed(arg: Option<&&str>) -> Result<u32, String> {
+    arg.map_or(Ok(SUBSTEPS_PER_STEP), |arg| parse_arg(arg))
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse().map_err(|_| format!("invalid value '{}'", arg))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,247 @@
+use super::*;
+
+use crate::model::game::GameState;
+use crate::model::speed::SUBSTEPS_PER_STEP;
+use crate::test_util;
+
+// Uses the fuse rule, so that the replays hold every kind of entry.
+fn get_config() -> GameConfig {
+    GameConfig {
+        fuse_delay: Some(2),
+        ..test_util::get_config()
+    }
+}
+
+fn run_game(config: &GameConfig) -> Game {
+    let mut game = config.build();
+    game.handle_command(RouterCommand::RIGHT);
+    game.update_state();
+    game.handle_command(RouterCommand::DOWN);
+    for _ in 0..3 {
+        game.update_state();
+    }
+    game.handle_command(RouterCommand::PAUSE);
+    game.update_state();
+    game.handle_command(RouterCommand::PAUSE);
+    game.handle_command(RouterCommand::LEFT);
+    for _ in 0..5 {
+        game.update_state();
+    }
+    game
+}
+
+#[test]
+fn replay_record() {
+    let config = get_config();
+    let game = run_game(&config);
+    let replay = Replay::record(&config, &game);
+
+    assert_eq!(&config, replay.config());
+    assert_eq!(10, replay.ticks());
+    assert_eq!(game.command_log(), replay.commands());
+}
+
+#[test]
+fn replay_reproduces_game() {
+    let config = get_config();
+    let game = run_game(&config);
+    let replayed = Replay::record(&config, &game).play();
+
+    assert_eq!(game.tick(), replayed.tick());
+    assert_eq!(game.state(), replayed.state());
+    assert_eq!(game.spider().pos(), replayed.spider().pos());
+    assert_eq!(game.score().points(), replayed.score().points());
+    assert_eq!(game.lives(), replayed.lives());
+    assert_eq!(game.command_log(), replayed.command_log());
+    for (enemy, replayed_enemy) in game.enemies().iter().zip(replayed.enemies()) {
+        assert_eq!(enemy.pos(), replayed_enemy.pos());
+    }
+}
+
+#[test]
+fn replay_player_steps() {
+    let config = get_config();
+    let replay = Replay::new(
+        config.clone(),
+        2,
+        vec![TimedCommand {
+            tick: 0,
+            command: RouterCommand::RIGHT,
+        }],
+    );
+
+    let mut game = config.build();
+    let mut player = ReplayPlayer::new(replay);
+
+    assert!(player.step(&mut game));
+    assert_eq!(GameState::Running, game.state());
+    assert_eq!(&Point::new(6, 0), game.spider().pos());
+    assert!(player.step(&mut game));
+    assert!(!player.step(&mut game));
+    assert_eq!(2, game.tick());
+    assert_eq!(&Point::new(7, 0), game.spider().pos());
+}
+
+#[test]
+fn replay_player_takes_due_commands() {
+    let command = |tick| TimedCommand {
+        tick,
+        command: RouterCommand::RIGHT,
+    };
+    let replay = Replay::new(get_config(), 5, vec![command(0), command(0), command(3)]);
+    let mut player = ReplayPlayer::new(replay);
+
+    assert_eq!(&[command(0), command(0)], player.take_due_commands(1));
+    assert!(player.take_due_commands(2).is_empty());
+    assert_eq!(&[command(3)], player.take_due_commands(4));
+    assert!(player.take_due_commands(5).is_empty());
+}
+
+#[test]
+fn replay_text_round_trip() {
+    let config = get_config();
+    let replay = Replay::record(&config, &run_game(&config));
+
+    assert_eq!(Ok(replay.clone()), Replay::parse(&replay.to_string()));
+}
+
+#[test]
+fn replay_reproduces_speeds() {
+    let mut config = get_config();
+    config.spider_speed = 2 * SUBSTEPS_PER_STEP;
+    for enemy in &mut config.enemies {
+        match enemy {
+            EnemyConfig::Snake { speed, .. } | EnemyConfig::Sparx { speed, .. } => *speed = 3,
+        }
+    }
+    let game = run_game(&config);
+    let replay = Replay::parse(&Replay::record(&config, &game).to_string()).unwrap();
+    let replayed = replay.play();
+
+    assert_eq!(&config, replay.config());
+    assert_eq!(game.spider().pos(), replayed.spider().pos());
+    for (enemy, replayed_enemy) in game.enemies().iter().zip(replayed.enemies()) {
+        assert_eq!(enemy.pos(), replayed_enemy.pos());
+    }
+}
+
+#[test]
+fn replay_parse() {
+    let text = "
+        # A short replay.
+        seed 3
+        field 20 10
+        spider 0 0
+        snake 5 5 1
+        sparx 20 10 clockwise 2
+
+        command 0 RIGHT
+        command 4 DOWN
+    ";
+    let replay = Replay::parse(text).unwrap();
+
+    assert_eq!(3, replay.config().seed);
+    assert_eq!(20, replay.config().width);
+    assert_eq!(10, replay.config().height);
+    assert_eq!(
+        vec![
+            EnemyConfig::Snake {
+                pos: Point::new(5, 5),
+                aggression: 1.0,
+                speed: SUBSTEPS_PER_STEP
+            },
+            EnemyConfig::Sparx {
+                pos: Point::new(20, 10),
+                orientation: Orientation::Clockwise,
+                speed: 2
+            },
+        ],
+        replay.config().enemies
+    );
+    assert_eq!(4, replay.ticks());
+    assert_eq!(2, replay.commands().len());
+}
+
+#[test]
+fn replay_parse_errors() {
+    let error = Replay::parse("seed 1\nfield 10\n").unwrap_err();
+    assert_eq!(2, error.line());
+    assert_eq!(
+        "line 2: 'field' expects 2 values but got 1",
+        error.to_string()
+    );
+
+    let error = Replay::parse("spider 1").unwrap_err();
+    assert_eq!(
+        "line 1: 'spider' expects 2 to 3 values but got 1",
+        error.to_string()
+    );
+
+    let error = Replay::parse("seed one").unwrap_err();
+    assert_eq!("line 1: invalid value 'one'", error.to_string());
+
+    let error = Replay::parse("command 0 JUMP").unwrap_err();
+    assert_eq!("line 1: unknown command 'JUMP'", error.to_string());
+
+    let error = Replay::parse("command 5 UP\ncommand 2 UP").unwrap_err();
+    assert_eq!(
+        "line 2: commands are not ordered by tick",
+        error.to_string()
+    );
+
+    let error = Replay::parse("\n\nsparx 1 1 sideways").unwrap_err();
+    assert_eq!("line 3: unknown orientation 'sideways'", error.to_string());
+
+    let error = Replay::parse("teleport 1 1").unwrap_err();
+    assert_eq!("line 1: unknown entry 'teleport'", error.to_string());
+}
+
+#[test]
+fn replay_parse_invalid_values() {
+    let error = Replay::parse("field 0 0").unwrap_err();
+    assert_eq!("line 1: invalid field size 0x0", error.to_string());
+
+    let error = Replay::parse("field 10 -4").unwrap_err();
+    assert_eq!("line 1: invalid field size 10x-4", error.to_string());
+
+    let error = Replay::parse("snake 1 1 NaN").unwrap_err();
+    assert_eq!("line 1: aggression NaN is not in [0, 1]", error.to_string());
+
+    let error = Replay::parse("snake 1 1 inf").unwrap_err();
+    assert_eq!("line 1: aggression inf is not in [0, 1]", error.to_string());
+
+    let error = Replay::parse("snake 1 1 1.5").unwrap_err();
+    assert_eq!("line 1: aggression 1.5 is not in [0, 1]", error.to_string());
+}
+
+#[test]
+fn replay_parse_positions_outside_field() {
+    // Positions are checked against the field even if it is given after them.
+    let error = Replay::parse(
+        "spider 11 0
+field 10 4",
+    )
+    .unwrap_err();
+    assert_eq!(
+        "line 1: position 11 0 is outside the 10x4 field",
+        error.to_string()
+    );
+
+    let error = Replay::parse(
+        "field 10 4
+sparx 10 4 clockwise
+snake -1 2 0.5",
+    )
+    .unwrap_err();
+    assert_eq!(
+        "line 3: position -1 2 is outside the 10x4 field",
+        error.to_string()
+    );
+
+    assert!(Replay::parse(
+        "field 10 4
+spider 10 4
+snake 0 0 0"
+    )
+    .is_ok());
+}
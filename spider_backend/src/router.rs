@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::model::game::Game;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(non_camel_case_types)]
+pub enum RouterCommand {
+    UP,
+    DOWN,
+    LEFT,
+    RIGHT,
+    STOP,
+    PAUSE,
+    DRAW,
+    RELEASE_DRAW,
+    TOGGLE_DRAW_MODE,
+    SPEED_UP,
+    SLOW_DOWN,
+    RESTART,
+    QUIT,
+    SAVE,
+    LOAD,
+}
+
+const ALL_COMMANDS: [RouterCommand; 15] = [
+    RouterCommand::UP,
+    RouterCommand::DOWN,
+    RouterCommand::LEFT,
+    RouterCommand::RIGHT,
+    RouterCommand::STOP,
+    RouterCommand::PAUSE,
+    RouterCommand::DRAW,
+    RouterCommand::RELEASE_DRAW,
+    RouterCommand::TOGGLE_DRAW_MODE,
+    RouterCommand::SPEED_UP,
+    RouterCommand::SLOW_DOWN,
+    RouterCommand::RESTART,
+    RouterCommand::QUIT,
+    RouterCommand::SAVE,
+    RouterCommand::LOAD,
+];
+
+impl RouterCommand {
+    pub fn all() -> &'static [RouterCommand] {
+        &ALL_COMMANDS
+    }
+
+    /// The commands a key can be bound to, see `is_bindable`.
+    pub fn bindable() -> impl Iterator<Item = RouterCommand> {
+        Self::all()
+            .iter()
+            .copied()
+            .filter(RouterCommand::is_bindable)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RouterCommand::UP => "UP",
+            RouterCommand::DOWN => "DOWN",
+            RouterCommand::LEFT => "LEFT",
+            RouterCommand::RIGHT => "RIGHT",
+            RouterCommand::STOP => "STOP",
+            RouterCommand::PAUSE => "PAUSE",
+            RouterCommand::DRAW => "DRAW",
+            RouterCommand::RELEASE_DRAW => "RELEASE_DRAW",
+            RouterCommand::TOGGLE_DRAW_MODE => "TOGGLE_DRAW_MODE",
+            RouterCommand::SPEED_UP => "SPEED_UP",
+            RouterCommand::SLOW_DOWN => "SLOW_DOWN",
+            RouterCommand::RESTART => "RESTART",
+            RouterCommand::QUIT => "QUIT",
+            RouterCommand::SAVE => "SAVE",
+            RouterCommand::LOAD => "LOAD",
+        }
+    }
+
+    /// Whether the command sets the direction of the spider.
+    pub fn is_direction(&self) -> bool {
+        matches!(
+            self,
+            RouterCommand::UP
+                | RouterCommand::DOWN
+                | RouterCommand::LEFT
+                | RouterCommand::RIGHT
+                | RouterCommand::STOP
+        )
+    }
+
+    /// Whether the command controls the session rather than the game. Session commands are
+    /// carried out by the frontend when it sees the event the game emits for them, see
+    /// `GameEvent::RestartRequested`. They are applied right away instead of being queued, even
+    /// when the game isn't running, and they are not logged, so replays don't repeat them.
+    pub fn is_session(&self) -> bool {
+        matches!(
+            self,
+            RouterCommand::RESTART
+                | RouterCommand::QUIT
+                | RouterCommand::SAVE
+                | RouterCommand::LOAD
+        )
+    }
+
+    /// The command to issue when the key bound to this command is released, for commands that
+    /// are held.
+    pub fn release_command(&self) -> Option<RouterCommand> {
+        match self {
+            RouterCommand::DRAW => Some(RouterCommand::RELEASE_DRAW),
+            _ => None,
+        }
+    }
+
+    /// Whether a key can be bound to the command. The release commands are issued by the router
+    /// itself and can't be bound.
+    pub fn is_bindable(&self) -> bool {
+        Self::all()
+            .iter()
+            .all(|command| command.release_command() != Some(*self))
+    }
+
+    pub fn from_name(name: &str) -> Option<RouterCommand> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|command| command.name() == name)
+    }
+}
+
+/// A command together with the tick it took effect on, see `Game::tick`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedCommand {
+    pub tick: u64,
+    pub command: RouterCommand,
+}
+
+pub(crate) fn command_to_handler(command: RouterCommand) -> fn(&mut Game) {
+    match command {
+        RouterCommand::UP => Game::handle_up,
+        RouterCommand::DOWN => Game::handle_down,
+        RouterCommand::LEFT => Game::handle_left,
+        RouterCommand::RIGHT => Game::handle_right,
+        RouterCommand::STOP => Game::handle_stop,
+        RouterCommand::PAUSE => Game::handle_pause,
+        RouterCommand::DRAW => Game::handle_draw,
+        RouterCommand::RELEASE_DRAW => Game::handle_release_draw,
+        RouterCommand::TOGGLE_DRAW_MODE => Game::handle_toggle_draw_mode,
+        RouterCommand::SPEED_UP => Game::handle_speed_up,
+        RouterCommand::SLOW_DOWN => Game::handle_slow_down,
+        RouterCommand::RESTART => Game::handle_restart,
+        RouterCommand::QUIT => Game::handle_quit,
+        RouterCommand::SAVE => Game::handle_save,
+        RouterCommand::LOAD => Game::handle_load,
+    }
+}
+
+/// How the direction keys steer the spider.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ControlScheme {
+    /// A direction key sets the spider's direction until another direction or `STOP` is given.
+    #[default]
+    Toggle,
+    /// The spider only moves while a direction key is held. Of several held direction keys the
+    /// one pressed last wins. Needs a frontend that reports key releases.
+    Hold,
+}
+
+impl ControlScheme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ControlScheme::Toggle => "toggle",
+            ControlScheme::Hold => "hold",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ControlScheme> {
+        [ControlScheme::Toggle, ControlScheme::Hold]
+            .iter()
+            .copied()
+            .find(|scheme| scheme.name() == name)
+    }
+}
+
+// The commands that move the spider while their key is held in `ControlScheme::Hold`.
+fn is_held_direction(command: RouterCommand) -> bool {
+    command.is_direction() && command != RouterCommand::STOP
+}
+
+#[derive(Debug)]
+pub struct Router<KeyT>
+where
+    KeyT: Eq,
+    KeyT: std::hash::Hash,
+{
+    key_to_command: HashMap<KeyT, RouterCommand>,
+    control_scheme: ControlScheme,
+    // The held direction keys in `ControlScheme::Hold`, the last pressed one last.
+    held_keys: Vec<KeyT>,
+}
+
+impl<KeyT> Router<KeyT>
+where
+    KeyT: Eq,
+    KeyT: std::hash::Hash,
+{
+    pub fn new() -> Router<KeyT> {
+        Router {
+            key_to_command: HashMap::new(),
+            control_scheme: ControlScheme::default(),
+            held_keys: Vec::new(),
+        }
+    }
+
+    pub fn control_scheme(&self) -> ControlScheme {
+        self.control_scheme
+    }
+
+    pub fn set_control_scheme(&mut self, control_scheme: ControlScheme) {
+        self.control_scheme = control_scheme;
+        self.held_keys.clear();
+    }
+
+    pub fn bind(&mut self, key: KeyT, command: RouterCommand) -> Option<RouterCommand> {
+        self.key_to_command.insert(key, command)
+    }
+
+    /// Removes the binding of `key`, returning the command it was bound to.
+    pub fn unbind(&mut self, key: &KeyT) -> Option<RouterCommand> {
+        self.held_keys.retain(|held| held != key);
+        self.key_to_command.remove(key)
+    }
+
+    /// Removes all bindings of `command`.
+    pub fn unbind_command(&mut self, command: RouterCommand) {
+        self.key_to_command.retain(|_, bound| *bound != command);
+    }
+
+    /// The command bound to `key`, if any.
+    pub fn command(&self, key: &KeyT) -> Option<RouterCommand> {
+        self.key_to_command.get(key).copied()
+    }
+
+    /// All keys bound to `command`, in no particular order.
+    pub fn keys(&self, command: RouterCommand) -> Vec<&KeyT> {
+        self.key_to_command
+            .iter()
+            .filter(|(_, bound)| **bound == command)
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Queues the command bound to `key` on `game`, see `Game::queue_command`. Session commands
+    /// are handled right away, see `RouterCommand::is_session`.
+    pub fn route(&mut self, key: KeyT, game: &mut Game) {
+        let command = match self.key_to_command.get(&key) {
+            Some(command) => *command,
+            None => return,
+        };
+
+        if command.is_session() {
+            game.handle_command(command);
+            return;
+        }
+
+        if self.control_scheme == ControlScheme::Hold && is_held_direction(command) {
+            // Repeated presses of a held key don't change anything.
+            if self.held_keys.last() == Some(&key) {
+                return;
+            }
+            self.held_keys.retain(|held| *held != key);
+            self.held_keys.push(key);
+        }
+
+        game.queue_command(command);
+    }
+
+    /// Handles the release of a key, for frontends that report key releases.
+    pub fn route_release(&mut self, key: KeyT, game: &mut Game) {
+        let command = match self.key_to_command.get(&key) {
+            Some(command) => *command,
+            None => return,
+        };
+
+        if self.control_scheme == ControlScheme::Hold && is_held_direction(command) {
+            let was_last = self.held_keys.last() == Some(&key);
+            self.held_keys.retain(|held| *held != key);
+            if was_last {
+                // Fall back to the direction of the key pressed before, if it is still held.
+                let next_command = self
+                    .held_keys
+                    .last()
+                    .and_then(|held| self.key_to_command.get(held))
+                    .copied()
+                    .unwrap_or(RouterCommand::STOP);
+                game.queue_command(next_command);
+            }
+            return;
+        }
+
+        if let Some(release_command) = command.release_command() {
+            game.queue_command(release_command);
+        }
+    }
+}
+
+impl<KeyT> Default for Router<KeyT>
+where
+    KeyT: Eq,
+    KeyT: std::hash::Hash,
+{
+    fn default() -> Router<KeyT> {
+        Router::new()
+    }
+}
+
+#[cfg(test)]
+mod tests;
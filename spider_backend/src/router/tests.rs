@@ -0,0 +1,217 @@
+use super::*;
+
+use crate::model::config::GameConfig;
+use crate::model::event::GameEvent;
+use crate::model::point::Direction;
+
+#[test]
+fn router_command_names() {
+    for command in RouterCommand::all() {
+        assert_eq!(Some(*command), RouterCommand::from_name(command.name()));
+    }
+
+    assert_eq!(None, RouterCommand::from_name("JUMP"));
+}
+
+#[test]
+fn router_release_commands_not_bindable() {
+    assert!(RouterCommand::DRAW.is_bindable());
+    assert!(!RouterCommand::RELEASE_DRAW.is_bindable());
+    assert_eq!(
+        RouterCommand::all().len() - 1,
+        RouterCommand::bindable().count()
+    );
+}
+
+#[test]
+fn router_routes_bound_keys() {
+    let mut game = GameConfig::default().build();
+    let mut router = Router::new();
+
+    assert_eq!(None, router.bind('d', RouterCommand::RIGHT));
+    assert_eq!(
+        Some(RouterCommand::RIGHT),
+        router.bind('d', RouterCommand::DOWN)
+    );
+
+    router.route('x', &mut game);
+    assert!(game.queued_commands().is_empty());
+
+    router.route('d', &mut game);
+    assert_eq!(
+        vec![RouterCommand::DOWN],
+        game.queued_commands().iter().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(Direction::NONE, game.spider().get_dir());
+
+    // The command takes effect with the next update.
+    game.update_state();
+    assert_eq!(Direction::DOWN, game.spider().get_dir());
+    assert!(game.queued_commands().is_empty());
+}
+
+#[test]
+fn router_commands_logged_with_tick() {
+    let mut game = GameConfig::default().build();
+    let mut router = Router::new();
+    router.bind('d', RouterCommand::DOWN);
+    router.bind('p', RouterCommand::PAUSE);
+
+    router.route('d', &mut game);
+    game.update_state();
+    game.update_state();
+    router.route('p', &mut game);
+    game.update_state();
+
+    assert_eq!(
+        &[
+            TimedCommand {
+                tick: 0,
+                command: RouterCommand::DOWN
+            },
+            TimedCommand {
+                tick: 2,
+                command: RouterCommand::PAUSE
+            },
+        ],
+        game.command_log()
+    );
+}
+
+#[test]
+fn router_handles_session_commands_right_away() {
+    let mut game = GameConfig::default().build();
+    let mut router = Router::new();
+    router.bind('q', RouterCommand::QUIT);
+    router.bind('+', RouterCommand::SPEED_UP);
+
+    router.route('q', &mut game);
+    assert!(game.queued_commands().is_empty());
+    assert_eq!(&[GameEvent::QuitRequested], game.events());
+
+    // Other commands are game input, even if they don't move the spider.
+    router.route('+', &mut game);
+    assert_eq!(0, game.speed_level());
+    game.update_state();
+    assert_eq!(1, game.speed_level());
+
+    assert_eq!(
+        &[TimedCommand {
+            tick: 0,
+            command: RouterCommand::SPEED_UP
+        }],
+        game.command_log()
+    );
+}
+
+#[test]
+fn router_routes_key_releases() {
+    let mut game = GameConfig::default().build();
+    let mut router = Router::new();
+    router.bind('x', RouterCommand::DRAW);
+    router.bind('d', RouterCommand::DOWN);
+
+    router.route('x', &mut game);
+    game.update_state();
+    assert!(game.spider().is_draw_held());
+
+    // Only held commands react to releases.
+    router.route_release('d', &mut game);
+    assert!(game.queued_commands().is_empty());
+
+    router.route_release('x', &mut game);
+    game.update_state();
+    assert!(!game.spider().is_draw_held());
+}
+
+#[test]
+fn control_scheme_names() {
+    for scheme in &[ControlScheme::Toggle, ControlScheme::Hold] {
+        assert_eq!(Some(*scheme), ControlScheme::from_name(scheme.name()));
+    }
+
+    assert_eq!(None, ControlScheme::from_name("tank"));
+}
+
+fn get_hold_router() -> Router<char> {
+    let mut router = Router::new();
+    router.set_control_scheme(ControlScheme::Hold);
+    router.bind('d', RouterCommand::DOWN);
+    router.bind('r', RouterCommand::RIGHT);
+    router.bind('x', RouterCommand::DRAW);
+    router
+}
+
+fn queued(game: &mut Game) -> Vec<RouterCommand> {
+    let commands = game.queued_commands().iter().copied().collect();
+    while !game.queued_commands().is_empty() {
+        game.update_state();
+    }
+    commands
+}
+
+#[test]
+fn router_hold_stops_on_release() {
+    let mut game = GameConfig::default().build();
+    let mut router = get_hold_router();
+
+    router.route('d', &mut game);
+    // Repeated presses while the key is held are ignored.
+    router.route('d', &mut game);
+    assert_eq!(vec![RouterCommand::DOWN], queued(&mut game));
+
+    router.route_release('d', &mut game);
+    assert_eq!(vec![RouterCommand::STOP], queued(&mut game));
+    assert_eq!(Direction::NONE, game.spider().get_dir());
+}
+
+#[test]
+fn router_hold_last_pressed_wins() {
+    let mut game = GameConfig::default().build();
+    let mut router = get_hold_router();
+
+    router.route('d', &mut game);
+    router.route('r', &mut game);
+    assert_eq!(
+        vec![RouterCommand::DOWN, RouterCommand::RIGHT],
+        queued(&mut game)
+    );
+
+    // Releasing the key pressed last falls back to the other held key.
+    router.route_release('r', &mut game);
+    assert_eq!(vec![RouterCommand::DOWN], queued(&mut game));
+
+    // Releasing a key that doesn't steer changes nothing.
+    router.route('r', &mut game);
+    router.route_release('d', &mut game);
+    assert_eq!(vec![RouterCommand::RIGHT], queued(&mut game));
+
+    router.route_release('r', &mut game);
+    assert_eq!(vec![RouterCommand::STOP], queued(&mut game));
+}
+
+#[test]
+fn router_hold_keeps_held_commands() {
+    let mut game = GameConfig::default().build();
+    let mut router = get_hold_router();
+
+    router.route('x', &mut game);
+    router.route_release('x', &mut game);
+    assert_eq!(
+        vec![RouterCommand::DRAW, RouterCommand::RELEASE_DRAW],
+        queued(&mut game)
+    );
+}
+
+#[test]
+fn router_toggle_ignores_direction_releases() {
+    let mut game = GameConfig::default().build();
+    let mut router = get_hold_router();
+    router.set_control_scheme(ControlScheme::Toggle);
+
+    router.route('d', &mut game);
+    router.route('d', &mut game);
+    router.route_release('d', &mut game);
+    // Repeated presses of one key give one command, the release gives none.
+    assert_eq!(vec![RouterCommand::DOWN], queued(&mut game));
+}
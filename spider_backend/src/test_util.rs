@@ -0,0 +1,52 @@
+// Fixtures shared by the tests of this crate and, through the `test-util` feature, by the tests of
+// the frontends.
+
+use crate::model::config::{EnemyConfig, GameConfig};
+use crate::model::field::Field;
+use crate::model::point::Point;
+use crate::model::rectilinear::Path;
+use crate::model::sparx::Orientation;
+use crate::model::speed::SUBSTEPS_PER_STEP;
+
+/// A small game: a 10x4 field with the spider in the middle of the upper edge, a snake in the left
+/// half and a sparx in the lower right corner. Moving down from the start cuts the field in half.
+pub fn get_config() -> GameConfig {
+    GameConfig {
+        width: 10,
+        height: 4,
+        spider_pos: Point::new(5, 0),
+        spider_speed: SUBSTEPS_PER_STEP,
+        enemies: vec![
+            EnemyConfig::Snake {
+                pos: Point::new(1, 1),
+                aggression: 0.5,
+                speed: SUBSTEPS_PER_STEP,
+            },
+            EnemyConfig::Sparx {
+                pos: Point::new(10, 4),
+                orientation: Orientation::AntiClockwise,
+                speed: SUBSTEPS_PER_STEP,
+            },
+        ],
+        claim_threshold: 90,
+        lives: 3,
+        fuse_delay: None,
+        seed: 7,
+    }
+}
+
+/// Cuts the field along a vertical line through its middle and claims the right half.
+pub fn claim_right_half(field: &mut Field) {
+    let half_width = field.width() / 2;
+    let points = [
+        Point::new(half_width, 0),
+        Point::new(half_width, field.height()),
+    ];
+    let path = Path::with_points(points.iter()).unwrap();
+    let (poly1, poly2) = field.free_polygon().cut(&path).unwrap();
+    if poly1.is_inside(&Point::new(1, 1)) {
+        field.cut(poly1, poly2);
+    } else {
+        field.cut(poly2, poly1);
+    }
+}
@@ -0,0 +1,155 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gtk::prelude::*;
+
+use spider_backend::bindings::Bindings;
+use spider_backend::router::{ControlScheme, RouterCommand};
+
+const CAPTURE_LABEL: &str = "Press a key…";
+const NO_KEYS_LABEL: &str = "(none)";
+
+// The key buttons of the dialog, one for every command that can be bound.
+type KeyButtons = Rc<Vec<(RouterCommand, gtk::Button)>>;
+
+/// Shows a dialog that edits `bindings`. Every command that can be bound is listed with its keys;
+/// clicking the keys of a command adds the next pressed key to them, Escape cancels. Returns the
+/// edited bindings, or `None` if the dialog was cancelled.
+pub fn run(window: &gtk::ApplicationWindow, bindings: Bindings) -> Option<Bindings> {
+    let dialog = gtk::Dialog::new_with_buttons(
+        Some("Key bindings"),
+        Some(window),
+        gtk::DialogFlags::MODAL,
+        &[
+            ("_Cancel", gtk::ResponseType::Cancel),
+            ("_Save", gtk::ResponseType::Accept),
+        ],
+    );
+
+    let bindings = Rc::new(RefCell::new(bindings));
+    // The command whose keys wait for the next key press.
+    let capturing = Rc::new(Cell::new(None));
+
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+    grid.set_border_width(12);
+
+    let scheme_combo = gtk::ComboBoxText::new();
+    for control_scheme in &[ControlScheme::Toggle, ControlScheme::Hold] {
+        scheme_combo.append(Some(control_scheme.name()), control_scheme.name());
+    }
+    let control_scheme = bindings.borrow().control_scheme().unwrap_or_default();
+    scheme_combo.set_active_id(Some(control_scheme.name()));
+    grid.attach(&left_aligned_label("Control scheme"), 0, 0, 1, 1);
+    grid.attach(&scheme_combo, 1, 0, 2, 1);
+
+    let mut buttons = Vec::new();
+    for (row, command) in RouterCommand::bindable().enumerate() {
+        let row = row as i32 + 1;
+        let keys_button = gtk::Button::new_with_label(NO_KEYS_LABEL);
+        let clear_button = gtk::Button::new_with_label("Clear");
+
+        grid.attach(&left_aligned_label(command.name()), 0, row, 1, 1);
+        grid.attach(&keys_button, 1, row, 1, 1);
+        grid.attach(&clear_button, 2, row, 1, 1);
+        buttons.push((command, keys_button, clear_button));
+    }
+    let key_buttons: KeyButtons = Rc::new(
+        buttons
+            .iter()
+            .map(|(command, keys_button, _)| (*command, keys_button.clone()))
+            .collect(),
+    );
+
+    for (command, keys_button, clear_button) in buttons {
+        let (capturing_rc, key_buttons_rc) = (capturing.clone(), key_buttons.clone());
+        let bindings_rc = bindings.clone();
+        keys_button.connect_clicked(move |button| {
+            // Only one command captures a key at a time.
+            update_labels(&key_buttons_rc, &bindings_rc.borrow());
+            capturing_rc.set(Some(command));
+            button.set_label(CAPTURE_LABEL);
+        });
+
+        let (capturing_rc, key_buttons_rc) = (capturing.clone(), key_buttons.clone());
+        let bindings_rc = bindings.clone();
+        clear_button.connect_clicked(move |_| {
+            capturing_rc.set(None);
+            bindings_rc.borrow_mut().set_keys(command, Vec::new());
+            update_labels(&key_buttons_rc, &bindings_rc.borrow());
+        });
+    }
+    update_labels(&key_buttons, &bindings.borrow());
+
+    let (capturing_rc, key_buttons_rc) = (capturing.clone(), key_buttons.clone());
+    let bindings_rc = bindings.clone();
+    dialog.connect_key_press_event(move |dialog, ev| {
+        let command = match capturing_rc.take() {
+            Some(command) => command,
+            None => return Inhibit(false),
+        };
+
+        let keyval = ev.get_keyval();
+        if keyval != gdk::enums::key::Escape {
+            if let Some(name) = gdk::keyval_name(keyval) {
+                add_key(dialog, &mut bindings_rc.borrow_mut(), command, &name);
+            }
+        }
+        update_labels(&key_buttons_rc, &bindings_rc.borrow());
+        // The key must not activate the focused button.
+        Inhibit(true)
+    });
+
+    dialog.get_content_area().add(&grid);
+    dialog.show_all();
+    let response = dialog.run();
+    let control_scheme = scheme_combo
+        .get_active_id()
+        .and_then(|name| ControlScheme::from_name(&name));
+    dialog.destroy();
+
+    if response != gtk::ResponseType::Accept {
+        return None;
+    }
+    let mut bindings = bindings.replace(Bindings::new());
+    bindings.set_control_scheme(control_scheme);
+    Some(bindings)
+}
+
+fn left_aligned_label(text: &str) -> gtk::Label {
+    let label = gtk::Label::new(Some(text));
+    label.set_xalign(0.0);
+    label
+}
+
+fn update_labels(key_buttons: &KeyButtons, bindings: &Bindings) {
+    for (command, button) in key_buttons.iter() {
+        let keys = bindings.keys(*command).unwrap_or_default();
+        if keys.is_empty() {
+            button.set_label(NO_KEYS_LABEL);
+        } else {
+            button.set_label(&keys.join(", "));
+        }
+    }
+}
+
+// Adds `key` to `command`. A key that is bound to another command is only moved if the player
+// agrees.
+fn add_key(dialog: &gtk::Dialog, bindings: &mut Bindings, command: RouterCommand, key: &str) {
+    match bindings.command(key) {
+        Some(bound) if bound != command => {
+            let question = format!(
+                "The key {} is already bound to {}. Bind it to {} instead?",
+                key,
+                bound.name(),
+                command.name()
+            );
+            if !super::confirm(dialog, &question) {
+                return;
+            }
+        }
+        _ => {}
+    }
+    bindings.add_key(command, key);
+}
@@ -2,9 +2,9 @@ use cairo;
 use gtk::prelude::*;
 use gtk::DrawingArea;
 
+use super::model::enemy::{CollisionShape, Enemy};
 use super::model::field::Field;
-use super::model::game::Game;
-use super::model::snake::Snake;
+use super::model::game::{Game, GameState};
 use super::model::spider::Spider;
 use super::model::rectilinear::Path;
 use super::model::rectilinear::Polygon;
@@ -12,6 +12,10 @@ use super::model::rectilinear::Polygon;
 const FIELD_FRAME_WIDTH: f64 = 0.4;
 const SPIDER_DOT_SIZE: f64 = 0.2;
 const SPIDER_PATH_WIDTH: f64 = 0.1;
+const ENEMY_PATH_WIDTH: f64 = 0.2;
+const CAPTION_FONT_SIZE: f64 = 2.0;
+const HUD_FONT_SIZE: f64 = 1.0;
+const HUD_MARGIN: f64 = 0.5;
 
 pub trait Drawable {
     fn draw(&self, drawing_area: &DrawingArea, cx: &cairo::Context);
@@ -89,12 +93,27 @@ impl Drawable for Polygon {
     }
 }
 
-impl Drawable for Snake {
+impl Drawable for dyn Enemy {
     fn draw(&self, _drawing_area: &DrawingArea, cx: &cairo::Context) {
         cx.set_source_rgb(1.0, 0.0, 0.0);
-        let spider_pos = self.pos();
-        draw_dot(cx, (spider_pos.x as f64, spider_pos.y as f64), SPIDER_DOT_SIZE);
-        cx.fill();
+        match self.collision_shape() {
+            CollisionShape::Point(pos) => {
+                draw_dot(cx, (pos.x as f64, pos.y as f64), SPIDER_DOT_SIZE);
+            }
+            CollisionShape::Path(path) => {
+                let points = path.points();
+                if let Some(first_point) = points.first() {
+                    cx.move_to(first_point.x as f64, first_point.y as f64);
+                }
+
+                for point in points.iter().skip(1) {
+                    cx.line_to(point.x as f64, point.y as f64);
+                }
+
+                cx.set_line_width(ENEMY_PATH_WIDTH);
+                cx.stroke();
+            }
+        }
     }
 }
 
@@ -108,10 +127,63 @@ impl Drawable for Game {
 
         self.field().draw_restore(drawing_area, cx);
         self.spider().draw_restore(drawing_area, cx);
-        self.snake().draw_restore(drawing_area, cx);
+        for enemy in self.active_enemies() {
+            enemy.draw_restore(drawing_area, cx);
+        }
+        if let Some(pos) = self.fuse().and_then(|fuse| fuse.pos(self.spider())) {
+            cx.save();
+            cx.set_source_rgb(1.0, 0.5, 0.0);
+            draw_dot(cx, (pos.x as f64, pos.y as f64), SPIDER_DOT_SIZE);
+            cx.restore();
+        }
+
+        let hud = format!("Score: {}  Lives: {}", self.score().points(), self.lives());
+        cx.save();
+        draw_hud(cx, &hud);
+        cx.restore();
+
+        if let Some(caption) = state_caption(self.state()) {
+            cx.save();
+            draw_caption(cx, caption, (field_width, field_height));
+            cx.restore();
+        }
     }
 }
 
+fn state_caption(state: GameState) -> Option<&'static str> {
+    match state {
+        GameState::Ready => Some("Press a direction key to start"),
+        GameState::Running => None,
+        GameState::Paused => Some("Paused"),
+        GameState::LifeLost => Some("Life lost, press a direction key to continue"),
+        GameState::LevelComplete => Some("Level complete"),
+        GameState::GameOver => Some("Game over"),
+    }
+}
+
+fn draw_hud(cx: &cairo::Context, text: &str) {
+    cx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Normal);
+    cx.set_font_size(HUD_FONT_SIZE);
+
+    let extents = cx.text_extents(text);
+    cx.set_source_rgb(0.0, 0.0, 0.0);
+    cx.move_to(HUD_MARGIN, HUD_MARGIN - extents.y_bearing);
+    cx.show_text(text);
+}
+
+fn draw_caption(cx: &cairo::Context, caption: &str, (width, height): (f64, f64)) {
+    cx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Bold);
+    cx.set_font_size(CAPTION_FONT_SIZE);
+
+    let extents = cx.text_extents(caption);
+    let x = (width - extents.width) / 2.0 - extents.x_bearing;
+    let y = (height - extents.height) / 2.0 - extents.y_bearing;
+
+    cx.set_source_rgb(0.0, 0.0, 0.0);
+    cx.move_to(x, y);
+    cx.show_text(caption);
+}
+
 fn draw_dot(cx: &cairo::Context, (x, y): (f64, f64), radius: f64) {
     cx.new_sub_path();
     cx.arc(x, y, radius, 0.0, 2.0 * std::f64::consts::PI);
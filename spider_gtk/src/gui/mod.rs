@@ -1,24 +1,75 @@
+mod bindings_dialog;
 pub mod draw;
-pub mod router;
 
+use std::cell::Cell;
+use std::path::Path;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 use gio::prelude::*;
 use gtk::prelude::*;
 use gtk::DrawingArea;
 
+use spider_backend::bindings::Bindings;
 use spider_backend::model;
 
 pub type DrawCallback = Rc<dyn Fn(&DrawingArea, &cairo::Context) -> Inhibit>;
 pub type KeyEventCallback = Rc<dyn Fn(&gdk::EventKey) -> Inhibit>;
+/// Saves the game to or loads it from the given file, returning an error message on failure.
+pub type FileCallback = Rc<dyn Fn(&Path) -> Result<(), String>>;
+/// Returns the key bindings that are in use, for editing.
+pub type BindingsGetter = Rc<dyn Fn() -> Bindings>;
+/// Puts edited key bindings to use and stores them, returning an error message on failure.
+pub type BindingsCallback = Rc<dyn Fn(&Bindings) -> Result<(), String>>;
+/// Starts the game over.
+pub type RestartCallback = Rc<dyn Fn()>;
+/// Called before every frame is drawn with the time that passed since the previous frame. Returns
+/// the session requests the window should carry out.
+pub type FrameCallback = Rc<dyn Fn(Duration) -> Vec<SessionRequest>>;
+
+pub const DEFAULT_FRAMES_PER_SECOND: u32 = 30;
+
+const SAVE_DIALOG: (&str, &str, gtk::FileChooserAction) =
+    ("Save game", "_Save", gtk::FileChooserAction::Save);
+const LOAD_DIALOG: (&str, &str, gtk::FileChooserAction) =
+    ("Load game", "_Open", gtk::FileChooserAction::Open);
+
+/// What the player asked the window to do, usually through a session command, see
+/// `RouterCommand::is_session`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionRequest {
+    /// Calls the restart callback once the player confirmed it.
+    Restart,
+    /// Closes the window once the player confirmed it.
+    Quit,
+    /// Asks for a file and passes it to the save callback.
+    Save,
+    /// Asks for a file and passes it to the load callback.
+    Load,
+}
+
 pub struct SpiderGui {
     app: gtk::Application,
     on_draw: DrawCallback,
     key_event_callback: KeyEventCallback,
+    key_release_callback: KeyEventCallback,
+    on_save: FileCallback,
+    on_load: FileCallback,
+    on_frame: FrameCallback,
+    bindings_callbacks: Option<(BindingsGetter, BindingsCallback)>,
+    on_restart: Option<RestartCallback>,
+    frames_per_second: u32,
 }
 
 impl SpiderGui {
-    pub fn new(on_draw: DrawCallback, key_event_callback: KeyEventCallback) -> SpiderGui {
+    pub fn new(
+        on_draw: DrawCallback,
+        key_event_callback: KeyEventCallback,
+        key_release_callback: KeyEventCallback,
+        on_save: FileCallback,
+        on_load: FileCallback,
+        on_frame: FrameCallback,
+    ) -> SpiderGui {
         let app =
             gtk::Application::new(None, Default::default()).expect("Initialization failed...");
 
@@ -26,16 +77,62 @@ impl SpiderGui {
             app,
             on_draw,
             key_event_callback,
+            key_release_callback,
+            on_save,
+            on_load,
+            on_frame,
+            bindings_callbacks: None,
+            on_restart: None,
+            frames_per_second: DEFAULT_FRAMES_PER_SECOND,
         };
         spider_gui
     }
 
+    /// Sets how often the window is redrawn. The value is clamped to at least 1.
+    pub fn set_frames_per_second(&mut self, frames_per_second: u32) {
+        self.frames_per_second = frames_per_second.max(1);
+    }
+
+    /// Enables the key bindings dialog, opened with Ctrl+K. `get_bindings` fills the dialog and
+    /// `on_bindings` receives the bindings when the dialog is saved.
+    pub fn set_bindings_callbacks(
+        &mut self,
+        get_bindings: BindingsGetter,
+        on_bindings: BindingsCallback,
+    ) {
+        self.bindings_callbacks = Some((get_bindings, on_bindings));
+    }
+
+    /// Sets what a confirmed `SessionRequest::Restart` does. Restart requests are ignored without
+    /// it.
+    pub fn set_restart_callback(&mut self, on_restart: RestartCallback) {
+        self.on_restart = Some(on_restart);
+    }
+
     pub fn run(self) {
         let app = self.app;
         let on_draw = self.on_draw;
         let key_event_callback = self.key_event_callback;
+        let key_release_callback = self.key_release_callback;
+        let on_save = self.on_save;
+        let on_load = self.on_load;
+        let on_frame = self.on_frame;
+        let bindings_callbacks = self.bindings_callbacks;
+        let on_restart = self.on_restart;
+        let frame_interval = 1000 / self.frames_per_second;
         app.connect_activate(move |application| {
-            Self::on_activate(application, on_draw.clone(), key_event_callback.clone())
+            Self::on_activate(
+                application,
+                on_draw.clone(),
+                key_event_callback.clone(),
+                key_release_callback.clone(),
+                on_save.clone(),
+                on_load.clone(),
+                on_frame.clone(),
+                bindings_callbacks.clone(),
+                on_restart.clone(),
+                frame_interval,
+            )
         });
         app.run(&std::env::args().collect::<Vec<_>>());
     }
@@ -44,19 +141,61 @@ impl SpiderGui {
         app: &gtk::Application,
         draw_callback: DrawCallback,
         key_event_callback: KeyEventCallback,
+        key_release_callback: KeyEventCallback,
+        on_save: FileCallback,
+        on_load: FileCallback,
+        on_frame: FrameCallback,
+        bindings_callbacks: Option<(BindingsGetter, BindingsCallback)>,
+        on_restart: Option<RestartCallback>,
+        frame_interval: u32,
     ) {
         let window = gtk::ApplicationWindow::new(app);
         let drawing_area = DrawingArea::new();
+        // Set while a dialog that isn't shown from a frame is open, so that the game waits for it.
+        let paused = Rc::new(Cell::new(false));
+
+        if let Some((get_bindings, on_bindings)) = bindings_callbacks {
+            Self::add_bindings_action(app, &window, paused.clone(), get_bindings, on_bindings);
+        }
 
-        window.add_events(gdk::EventMask::KEY_PRESS_MASK);
+        window.add_events(gdk::EventMask::KEY_PRESS_MASK | gdk::EventMask::KEY_RELEASE_MASK);
 
         window.connect_key_press_event(move |_, ev| key_event_callback(ev));
+        window.connect_key_release_event(move |_, ev| key_release_callback(ev));
 
         drawing_area.connect_draw(move |dr, cx| draw_callback(dr, cx));
         window.add(&drawing_area);
 
-        // TODO: Make the refresh rate a parameter.
-        gtk::timeout_add(500, move || {
+        let last_frame = Cell::new(Instant::now());
+        let frame_window = window.clone();
+        gtk::timeout_add(frame_interval, move || {
+            let now = Instant::now();
+            if paused.get() {
+                // The time the dialog was open doesn't count towards the next frame.
+                last_frame.set(now);
+                return Continue(true);
+            }
+            for request in on_frame(now - last_frame.replace(now)) {
+                // A stray key press must not end the game.
+                match request {
+                    SessionRequest::Restart => {
+                        if let Some(on_restart) = &on_restart {
+                            if confirm(&frame_window, "Start the game over?") {
+                                on_restart();
+                            }
+                        }
+                    }
+                    SessionRequest::Quit => {
+                        if confirm(&frame_window, "Quit the game?") {
+                            frame_window.close();
+                            return Continue(false);
+                        }
+                    }
+                    SessionRequest::Save => choose_file(&frame_window, SAVE_DIALOG, &on_save),
+                    SessionRequest::Load => choose_file(&frame_window, LOAD_DIALOG, &on_load),
+                }
+            }
+
             let width = drawing_area.get_allocated_width();
             let height = drawing_area.get_allocated_height();
             drawing_area.clone().queue_draw_area(0, 0, width, height);
@@ -64,4 +203,88 @@ impl SpiderGui {
         });
         window.show_all();
     }
+
+    // Adds an application action, activated by Ctrl+K, that shows the key bindings dialog. The
+    // action runs outside of the frames, so `paused` is set while the dialog is open.
+    fn add_bindings_action(
+        app: &gtk::Application,
+        window: &gtk::ApplicationWindow,
+        paused: Rc<Cell<bool>>,
+        get_bindings: BindingsGetter,
+        on_bindings: BindingsCallback,
+    ) {
+        let action = gio::SimpleAction::new("bindings", None);
+        let window = window.clone();
+        action.connect_activate(move |_, _| {
+            paused.set(true);
+            let bindings = bindings_dialog::run(&window, get_bindings());
+            paused.set(false);
+            let bindings = match bindings {
+                Some(bindings) => bindings,
+                None => return,
+            };
+            if let Err(message) = on_bindings(&bindings) {
+                show_error(&window, &message);
+            }
+        });
+
+        app.add_action(&action);
+        app.set_accels_for_action("app.bindings", &["<Primary>k"]);
+    }
+}
+
+// Asks for a file and passes it to `callback`.
+fn choose_file(
+    window: &gtk::ApplicationWindow,
+    (title, accept_label, chooser_action): (&str, &str, gtk::FileChooserAction),
+    callback: &FileCallback,
+) {
+    let dialog = gtk::FileChooserDialog::with_buttons(
+        Some(title),
+        Some(window),
+        chooser_action,
+        &[
+            ("_Cancel", gtk::ResponseType::Cancel),
+            (accept_label, gtk::ResponseType::Accept),
+        ],
+    );
+    dialog.set_do_overwrite_confirmation(true);
+
+    let response = dialog.run();
+    let filename = dialog.get_filename();
+    dialog.destroy();
+
+    if response != gtk::ResponseType::Accept {
+        return;
+    }
+    if let Some(filename) = filename {
+        if let Err(message) = callback(&filename) {
+            show_error(window, &message);
+        }
+    }
+}
+
+fn confirm<W: IsA<gtk::Window>>(parent: &W, question: &str) -> bool {
+    let dialog = gtk::MessageDialog::new(
+        Some(parent),
+        gtk::DialogFlags::MODAL,
+        gtk::MessageType::Question,
+        gtk::ButtonsType::YesNo,
+        question,
+    );
+    let response = dialog.run();
+    dialog.destroy();
+    response == gtk::ResponseType::Yes
+}
+
+fn show_error(window: &gtk::ApplicationWindow, message: &str) {
+    let dialog = gtk::MessageDialog::new(
+        Some(window),
+        gtk::DialogFlags::MODAL,
+        gtk::MessageType::Error,
+        gtk::ButtonsType::Close,
+        message,
+    );
+    dialog.run();
+    dialog.destroy();
 }
@@ -0,0 +1,208 @@
+mod runner;
+
+use std::process;
+
+use spider_backend::model::config::{EnemyConfig, GameConfig};
+use spider_backend::model::point::Point;
+use spider_backend::model::snake::DEFAULT_AGGRESSION;
+use spider_backend::model::sparx::Orientation;
+use spider_backend::model::speed::SUBSTEPS_PER_STEP;
+use spider_backend::replay::Replay;
+
+use runner::{Bot, EndCause, IdleBot, RandomBot, ScriptBot};
+
+const USAGE: &str = "\
+Usage: spider_headless [OPTIONS]
+
+Runs games without a display and prints a JSON summary of every game on its own line.
+
+Options:
+    --games N           Number of games to run, with consecutive seeds (default 1)
+    --seed N            Seed of the first game (default 0)
+    --ticks N           Maximum number of ticks per game (default 10000)
+    --width N           Width of the field (default 50)
+    --height N          Height of the field (default 20)
+    --snakes N          Number of snakes (default 1)
+    --sparx N           Number of sparx (default 1)
+    --aggression X      Aggression of the snakes between 0 and 1 (default 0.8)
+    --lives N           Lives of the spider (default 3)
+    --threshold N       Percentage of the field to claim (default 75)
+    --fuse N            Light a fuse after the spider stood still for N ticks while drawing
+    --bot NAME          Input of the games: random or idle (default random)
+    --turn-chance X     Probability of the random bot turning in a tick (default 0.1)
+    --replay FILE       Play the commands of a replay file instead of using a bot, can't be
+                        combined with the other options
+
+The exit code is 1 if any game panicked.";
+
+#[derive(Debug)]
+struct Options {
+    games: u64,
+    seed: u64,
+    ticks: u64,
+    config: GameConfig,
+    snakes: usize,
+    sparx: usize,
+    aggression: f64,
+    bot: String,
+    turn_chance: f64,
+    replay: Option<Replay>,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            games: 1,
+            seed: 0,
+            ticks: 10000,
+            config: GameConfig::default(),
+            snakes: 1,
+            sparx: 1,
+            aggression: DEFAULT_AGGRESSION,
+            bot: String::from("random"),
+            turn_chance: 0.1,
+            replay: None,
+        }
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {}", option))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, option))
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    // An option that has no effect on a replay, which brings its own configuration.
+    let mut game_option = None;
+
+    while let Some(arg) = args.next() {
+        let option = arg.as_str();
+        if option != "--replay" && option.starts_with("--") && game_option.is_none() {
+            game_option = Some(arg.clone());
+        }
+        match option {
+            "--games" => options.games = parse_value(option, args.next())?,
+            "--seed" => options.seed = parse_value(option, args.next())?,
+            "--ticks" => options.ticks = parse_value(option, args.next())?,
+            "--width" => options.config.width = parse_value(option, args.next())?,
+            "--height" => options.config.height = parse_value(option, args.next())?,
+            "--snakes" => options.snakes = parse_value(option, args.next())?,
+            "--sparx" => options.sparx = parse_value(option, args.next())?,
+            "--aggression" => options.aggression = parse_value(option, args.next())?,
+            "--lives" => options.config.lives = parse_value(option, args.next())?,
+            "--threshold" => options.config.claim_threshold = parse_value(option, args.next())?,
+            "--fuse" => options.config.fuse_delay = Some(parse_value(option, args.next())?),
+            "--bot" => options.bot = parse_value(option, args.next())?,
+            "--turn-chance" => options.turn_chance = parse_value(option, args.next())?,
+            "--replay" => {
+                let path: String = parse_value(option, args.next())?;
+                let text = std::fs::read_to_string(&path)
+                    .map_err(|err| format!("could not read {}: {}", path, err))?;
+                let replay = Replay::parse(&text).map_err(|err| format!("{}: {}", path, err))?;
+                options.replay = Some(replay);
+            }
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ => return Err(format!("unknown option {}", option)),
+        }
+    }
+
+    if let (Some(_), Some(option)) = (&options.replay, game_option) {
+        return Err(format!("{} can't be combined with --replay", option));
+    }
+    if options.bot != "random" && options.bot != "idle" {
+        return Err(format!("unknown bot {}", options.bot));
+    }
+    if options.config.width <= 0 || options.config.height <= 0 {
+        return Err(format!(
+            "invalid field size {}x{}",
+            options.config.width, options.config.height
+        ));
+    }
+    if !(0.0..=1.0).contains(&options.aggression) {
+        return Err(format!(
+            "aggression {} is not between 0 and 1",
+            options.aggression
+        ));
+    }
+    if !(0.0..=1.0).contains(&options.turn_chance) {
+        return Err(format!(
+            "turn chance {} is not between 0 and 1",
+            options.turn_chance
+        ));
+    }
+
+    Ok(options)
+}
+
+// The snakes start in the middle of the field and the sparx in its corners, walking in alternating
+// directions.
+fn enemies(options: &Options) -> Vec<EnemyConfig> {
+    let (width, height) = (options.config.width, options.config.height);
+    let corners = [
+        Point::new(width, height),
+        Point::new(0, height),
+        Point::new(width, 0),
+        Point::new(0, 0),
+    ];
+
+    let snakes = (0..options.snakes).map(|_| EnemyConfig::Snake {
+        pos: Point::new(width / 2, height / 2),
+        aggression: options.aggression,
+        speed: SUBSTEPS_PER_STEP,
+    });
+    let sparx = (0..options.sparx).map(|i| EnemyConfig::Sparx {
+        pos: corners[i % corners.len()],
+        orientation: if i % 2 == 0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::AntiClockwise
+        },
+        speed: SUBSTEPS_PER_STEP,
+    });
+
+    snakes.chain(sparx).collect()
+}
+
+fn main() {
+    let options = parse_options(std::env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}\n\n{}", message, USAGE);
+        process::exit(2);
+    });
+
+    let mut panicked = false;
+
+    if let Some(replay) = &options.replay {
+        let mut bot = ScriptBot::new(replay.clone());
+        let summary = runner::run(replay.config(), &mut bot, replay.ticks());
+        panicked |= matches!(summary.end, EndCause::Panic(_));
+        println!("{}", serde_json::to_string(&summary).unwrap());
+    } else {
+        let enemies = enemies(&options);
+        // The seeds wrap around instead of overflowing.
+        for seed in (0..options.games).map(|game| options.seed.wrapping_add(game)) {
+            let config = GameConfig {
+                enemies: enemies.clone(),
+                seed,
+                ..options.config.clone()
+            };
+            let mut bot: Box<dyn Bot> = match options.bot.as_str() {
+                "idle" => Box::new(IdleBot),
+                _ => Box::new(RandomBot::new(!seed, options.turn_chance)),
+            };
+
+            let summary = runner::run(&config, bot.as_mut(), options.ticks);
+            panicked |= matches!(summary.end, EndCause::Panic(_));
+            println!("{}", serde_json::to_string(&summary).unwrap());
+        }
+    }
+
+    if panicked {
+        process::exit(1);
+    }
+}
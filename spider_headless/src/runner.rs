@@ -0,0 +1,208 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+
+use spider_backend::model::config::GameConfig;
+use spider_backend::model::event::GameEvent;
+use spider_backend::model::game::{Game, GameRng, GameState};
+use spider_backend::replay::{Replay, ReplayPlayer};
+use spider_backend::router::RouterCommand;
+
+const DIRECTIONS: [RouterCommand; 4] = [
+    RouterCommand::UP,
+    RouterCommand::DOWN,
+    RouterCommand::LEFT,
+    RouterCommand::RIGHT,
+];
+
+/// Provides the input of a game in place of a player.
+pub trait Bot {
+    /// The commands to apply before the next update of `game`.
+    fn commands(&mut self, game: &Game) -> Vec<RouterCommand>;
+}
+
+/// Never gives any input, so the game never starts.
+#[derive(Debug, Default)]
+pub struct IdleBot;
+
+impl Bot for IdleBot {
+    fn commands(&mut self, _game: &Game) -> Vec<RouterCommand> {
+        Vec::new()
+    }
+}
+
+/// Holds the draw command and turns into a random direction from time to time.
+#[derive(Debug)]
+pub struct RandomBot {
+    rng: GameRng,
+    turn_probability: f64,
+}
+
+impl RandomBot {
+    pub fn new(seed: u64, turn_probability: f64) -> RandomBot {
+        RandomBot {
+            rng: GameRng::seed_from_u64(seed),
+            turn_probability: turn_probability.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Bot for RandomBot {
+    fn commands(&mut self, game: &Game) -> Vec<RouterCommand> {
+        let mut commands = Vec::new();
+        if !game.spider().is_draw_held() {
+            commands.push(RouterCommand::DRAW);
+        }
+
+        // The game waits for a direction after it is created and after the spider lost a life.
+        let waiting = matches!(game.state(), GameState::Ready | GameState::LifeLost);
+        if waiting || self.rng.gen_bool(self.turn_probability) {
+            commands.push(DIRECTIONS[self.rng.gen_range(0, DIRECTIONS.len())]);
+        }
+        commands
+    }
+}
+
+/// Replays the commands of a replay on the ticks they were recorded on.
+#[derive(Debug)]
+pub struct ScriptBot {
+    player: ReplayPlayer,
+}
+
+impl ScriptBot {
+    pub fn new(replay: Replay) -> ScriptBot {
+        ScriptBot {
+            player: ReplayPlayer::new(replay),
+        }
+    }
+}
+
+impl Bot for ScriptBot {
+    fn commands(&mut self, game: &Game) -> Vec<RouterCommand> {
+        self.player
+            .take_due_commands(game.tick())
+            .iter()
+            .map(|timed| timed.command)
+            .collect()
+    }
+}
+
+/// Why a run stopped.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndCause {
+    LevelComplete,
+    GameOver,
+    TickLimit,
+    /// The backend panicked, with the panic message.
+    Panic(String),
+}
+
+/// The machine-readable result of a single run.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub seed: u64,
+    pub ticks: u64,
+    pub claimed_area: i32,
+    pub claimed_percentage: i32,
+    pub score: u32,
+    pub lives: u32,
+    /// The number of enemies left in the free area.
+    pub enemies: usize,
+    /// The number of areas the spider claimed.
+    pub claims: u32,
+    /// The number of paths that were discarded without claiming an area.
+    pub abandoned_paths: u32,
+    pub end: EndCause,
+}
+
+// Counts the events of a run that are not visible in the final state of the game.
+#[derive(Debug, Default)]
+struct EventCounts {
+    claims: u32,
+    abandoned_paths: u32,
+}
+
+impl EventCounts {
+    fn count(&mut self, game: &mut Game) {
+        for event in game.drain_events() {
+            match event {
+                GameEvent::AreaClaimed { .. } => self.claims += 1,
+                GameEvent::PathAbandoned => self.abandoned_paths += 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Builds a game from `config` and updates it with the input of `bot` until the game is over, the
+/// level is complete or `max_ticks` ticks have passed. A panic in the backend, including one while
+/// building the game, ends the run instead of the program.
+pub fn run(config: &GameConfig, bot: &mut dyn Bot, max_ticks: u64) -> Summary {
+    let mut game = None;
+    let mut counts = EventCounts::default();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let game = game.get_or_insert_with(|| config.build());
+        drive(game, bot, max_ticks, &mut counts)
+    }));
+    let end = result.unwrap_or_else(|payload| EndCause::Panic(panic_message(payload.as_ref())));
+
+    match game {
+        Some(game) => Summary {
+            seed: game.seed(),
+            ticks: game.tick(),
+            claimed_area: game.field().claimed_area(),
+            claimed_percentage: game.field().claimed_percentage(),
+            score: game.score().points(),
+            lives: game.lives(),
+            enemies: game.active_enemies().count(),
+            claims: counts.claims,
+            abandoned_paths: counts.abandoned_paths,
+            end,
+        },
+        None => Summary {
+            seed: config.seed,
+            ticks: 0,
+            claimed_area: 0,
+            claimed_percentage: 0,
+            score: 0,
+            lives: config.lives,
+            enemies: config.enemies.len(),
+            claims: 0,
+            abandoned_paths: 0,
+            end,
+        },
+    }
+}
+
+fn drive(game: &mut Game, bot: &mut dyn Bot, max_ticks: u64, counts: &mut EventCounts) -> EndCause {
+    while game.tick() < max_ticks {
+        for command in bot.commands(game) {
+            game.handle_command(command);
+        }
+        game.update_state();
+        counts.count(game);
+
+        match game.state() {
+            GameState::LevelComplete => return EndCause::LevelComplete,
+            GameState::GameOver => return EndCause::GameOver,
+            _ => {}
+        }
+    }
+
+    EndCause::TickLimit
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+use super::*;
+
+use spider_backend::router::TimedCommand;
+use spider_backend::test_util;
+
+fn get_config() -> GameConfig {
+    GameConfig {
+        enemies: Vec::new(),
+        claim_threshold: 50,
+        seed: 1,
+        ..test_util::get_config()
+    }
+}
+
+#[test]
+fn run_idle_hits_tick_limit() {
+    let summary = run(&GameConfig::default(), &mut IdleBot, 20);
+
+    assert_eq!(20, summary.ticks);
+    assert_eq!(0, summary.claimed_area);
+    assert_eq!(EndCause::TickLimit, summary.end);
+}
+
+#[test]
+fn run_script_completes_level() {
+    let replay = Replay::new(
+        get_config(),
+        100,
+        vec![
+            TimedCommand {
+                tick: 0,
+                command: RouterCommand::DRAW,
+            },
+            TimedCommand {
+                tick: 0,
+                command: RouterCommand::DOWN,
+            },
+        ],
+    );
+    let mut bot = ScriptBot::new(replay);
+    let summary = run(&get_config(), &mut bot, 100);
+
+    assert_eq!(4, summary.ticks);
+    assert_eq!(20, summary.claimed_area);
+    assert_eq!(50, summary.claimed_percentage);
+    assert_eq!(1, summary.claims);
+    assert_eq!(0, summary.abandoned_paths);
+    assert_eq!(EndCause::LevelComplete, summary.end);
+}
+
+#[test]
+fn run_random_is_reproducible() {
+    let config = GameConfig::default();
+    let summary1 = run(&config, &mut RandomBot::new(3, 0.2), 500);
+    let summary2 = run(&config, &mut RandomBot::new(3, 0.2), 500);
+
+    assert_eq!(summary1, summary2);
+}
+
+#[test]
+fn run_catches_panics() {
+    struct PanicBot;
+
+    impl Bot for PanicBot {
+        fn commands(&mut self, _game: &Game) -> Vec<RouterCommand> {
+            panic!("bot failed");
+        }
+    }
+
+    let summary = run(&get_config(), &mut PanicBot, 100);
+
+    assert_eq!(EndCause::Panic(String::from("bot failed")), summary.end);
+}
+
+#[test]
+fn run_catches_panics_while_building() {
+    let config = GameConfig {
+        width: 0,
+        ..get_config()
+    };
+    let summary = run(&config, &mut IdleBot, 100);
+
+    assert!(matches!(summary.end, EndCause::Panic(_)));
+    assert_eq!(config.seed, summary.seed);
+    assert_eq!(0, summary.ticks);
+}
+
+#[test]
+fn summary_json() {
+    let summary = run(&get_config(), &mut IdleBot, 3);
+    let json = serde_json::to_string(&summary).unwrap();
+
+    assert_eq!(
+        r#"{"seed":1,"ticks":3,"claimed_area":0,"claimed_percentage":0,"score":0,"lives":3,"enemies":0,"claims":0,"abandoned_paths":0,"end":"tick_limit"}"#,
+        json
+    );
+}
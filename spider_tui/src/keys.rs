@@ -0,0 +1,65 @@
+use termion::event::Key;
+
+// The keys with a name other than their character.
+const NAMED_KEYS: [(&str, Key); 15] = [
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("Page_Up", Key::PageUp),
+    ("Page_Down", Key::PageDown),
+    ("Insert", Key::Insert),
+    ("Delete", Key::Delete),
+    ("BackSpace", Key::Backspace),
+    ("Escape", Key::Esc),
+    ("space", Key::Char(' ')),
+    ("Return", Key::Char('\n')),
+    ("Tab", Key::Char('\t')),
+];
+
+/// Turns a key name of the bindings file into a key. The names follow the GDK key names, so that
+/// one bindings file works for all frontends: `Up`, `space`, `Return`, `F1`, or the character
+/// itself, such as `a` or `+`. Keys that terminals can't tell apart from others, such as `Shift_L`,
+/// are not known.
+pub fn key_from_name(name: &str) -> Option<Key> {
+    NAMED_KEYS
+        .iter()
+        .find(|(key_name, _)| *key_name == name)
+        .map(|(_, key)| *key)
+        .or_else(|| function_key(name))
+        .or_else(|| single_char(name).map(Key::Char))
+}
+
+/// The name of `key`, see `key_from_name`, or `None` for keys without a name such as `Ctrl+A`.
+pub fn key_name(key: &Key) -> Option<String> {
+    if let Some((name, _)) = NAMED_KEYS.iter().find(|(_, named)| named == key) {
+        return Some(String::from(*name));
+    }
+    match key {
+        Key::F(number) => Some(format!("F{}", number)),
+        Key::Char(ch) => Some(ch.to_string()),
+        _ => None,
+    }
+}
+
+fn function_key(name: &str) -> Option<Key> {
+    let number: u8 = name.strip_prefix('F')?.parse().ok()?;
+    if (1..=12).contains(&number) {
+        Some(Key::F(number))
+    } else {
+        None
+    }
+}
+
+fn single_char(name: &str) -> Option<char> {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ch),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn key_names() {
+    assert_eq!(Some(Key::Up), key_from_name("Up"));
+    assert_eq!(Some(Key::Char(' ')), key_from_name("space"));
+    assert_eq!(Some(Key::Esc), key_from_name("Escape"));
+    assert_eq!(Some(Key::F(5)), key_from_name("F5"));
+    assert_eq!(Some(Key::Char('a')), key_from_name("a"));
+    assert_eq!(Some(Key::Char('ö')), key_from_name("ö"));
+    assert_eq!(Some(Key::Char('F')), key_from_name("F"));
+
+    assert_eq!(None, key_from_name("F13"));
+    assert_eq!(None, key_from_name("Shift_L"));
+    assert_eq!(None, key_from_name(""));
+}
+
+#[test]
+fn key_names_round_trip() {
+    for key in &[
+        Key::Up,
+        Key::Esc,
+        Key::Char(' '),
+        Key::Char('\n'),
+        Key::F(12),
+        Key::Char('+'),
+    ] {
+        let name = key_name(key).unwrap();
+        assert_eq!(Some(*key), key_from_name(&name));
+    }
+
+    assert_eq!(Some(String::from("space")), key_name(&Key::Char(' ')));
+    assert_eq!(None, key_name(&Key::Ctrl('c')));
+}
@@ -0,0 +1,209 @@
+mod keys;
+mod render;
+
+use std::io::{self, Write};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+use termion::screen::AlternateScreen;
+use termion::{clear, cursor};
+
+use spider_backend::bindings::{self, Bindings};
+use spider_backend::clock::GameClock;
+use spider_backend::model::config::GameConfig;
+use spider_backend::model::event::GameEvent;
+use spider_backend::model::game::Game;
+use spider_backend::router::{ControlScheme, Router, RouterCommand};
+
+const FRAME_DURATION: Duration = Duration::from_millis(33);
+// The commands shown in the help line, in order, with what they do.
+const HELP: [(RouterCommand, &str); 12] = [
+    (RouterCommand::UP, "up"),
+    (RouterCommand::DOWN, "down"),
+    (RouterCommand::LEFT, "left"),
+    (RouterCommand::RIGHT, "right"),
+    (RouterCommand::DRAW, "draw on/off"),
+    (RouterCommand::TOGGLE_DRAW_MODE, "fast/slow draw"),
+    (RouterCommand::STOP, "stop"),
+    (RouterCommand::PAUSE, "pause"),
+    (RouterCommand::SPEED_UP, "faster"),
+    (RouterCommand::SLOW_DOWN, "slower"),
+    (RouterCommand::RESTART, "restart"),
+    (RouterCommand::QUIT, "quit"),
+];
+
+fn default_router() -> Router<Key> {
+    let mut router = Router::new();
+
+    router.bind(Key::Down, RouterCommand::DOWN);
+    router.bind(Key::Up, RouterCommand::UP);
+    router.bind(Key::Left, RouterCommand::LEFT);
+    router.bind(Key::Right, RouterCommand::RIGHT);
+    router.bind(Key::Char(' '), RouterCommand::STOP);
+    router.bind(Key::Char('p'), RouterCommand::PAUSE);
+    router.bind(Key::Char('d'), RouterCommand::DRAW);
+    router.bind(Key::Char('f'), RouterCommand::TOGGLE_DRAW_MODE);
+    router.bind(Key::Char('+'), RouterCommand::SPEED_UP);
+    router.bind(Key::Char('-'), RouterCommand::SLOW_DOWN);
+    router.bind(Key::Char('r'), RouterCommand::RESTART);
+    router.bind(Key::Char('q'), RouterCommand::QUIT);
+    router.bind(Key::Esc, RouterCommand::QUIT);
+
+    router
+}
+
+// Applies the user's bindings file to `router`. Errors are reported and the default bindings kept.
+fn load_bindings(router: &mut Router<Key>) {
+    let path = match bindings::user_bindings_path() {
+        Some(path) => path,
+        None => return,
+    };
+
+    let result = match Bindings::load(&path) {
+        Ok(Some(bindings)) => bindings
+            .apply(router, keys::key_from_name)
+            .map_err(|err| err.to_string()),
+        Ok(None) => Ok(()),
+        Err(err) => Err(err.to_string()),
+    };
+    if let Err(message) = result {
+        eprintln!(
+            "Could not load key bindings from {}: {}",
+            path.display(),
+            message
+        );
+        eprintln!("Using the default key bindings.");
+        thread::sleep(Duration::from_secs(2));
+    }
+}
+
+// Lists the keys of the commands in `HELP` as they are bound in `router`. Unbound commands are left
+// out.
+fn help_line(router: &Router<Key>) -> String {
+    let entries: Vec<String> = HELP
+        .iter()
+        .filter_map(|(command, description)| {
+            let mut names: Vec<String> = router
+                .keys(*command)
+                .into_iter()
+                .filter_map(keys::key_name)
+                .collect();
+            if names.is_empty() {
+                return None;
+            }
+            // The router doesn't keep its keys in order.
+            names.sort();
+            Some(format!("{}: {}", names.join("/"), description))
+        })
+        .collect();
+    entries.join("  ")
+}
+
+// Raw mode turns Ctrl+C into a key press, so it always quits, whatever the bindings.
+fn is_quit_key(key: Key) -> bool {
+    key == Key::Ctrl('c')
+}
+
+// Reads keys from stdin on a separate thread, because reading blocks.
+fn spawn_key_reader() -> mpsc::Receiver<Key> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for key in io::stdin().keys() {
+            match key {
+                Ok(key) if sender.send(key).is_ok() => {}
+                _ => break,
+            }
+        }
+    });
+    receiver
+}
+
+// A new random game.
+fn new_game() -> Game {
+    GameConfig {
+        seed: rand::random(),
+        ..GameConfig::default()
+    }
+    .build()
+}
+
+fn main() -> io::Result<()> {
+    let mut game = new_game();
+    let mut clock = GameClock::new();
+    let mut router = default_router();
+    load_bindings(&mut router);
+    // Terminals don't report key releases, so the spider is always steered with
+    // `ControlScheme::Toggle` and the draw command is toggled instead of held.
+    router.set_control_scheme(ControlScheme::Toggle);
+    let help = help_line(&router);
+
+    let keys = spawn_key_reader();
+    let mut screen = AlternateScreen::from(io::stdout().into_raw_mode()?);
+    write!(screen, "{}{}", cursor::Hide, clear::All)?;
+
+    let mut draw_held = false;
+    let mut message = String::new();
+    let mut last_frame = Instant::now();
+    'frames: loop {
+        for key in keys.try_iter() {
+            if is_quit_key(key) {
+                break 'frames;
+            }
+            let is_draw_key = router.command(&key) == Some(RouterCommand::DRAW);
+            if is_draw_key && draw_held {
+                router.route_release(key, &mut game);
+            } else {
+                router.route(key, &mut game);
+            }
+            draw_held ^= is_draw_key;
+        }
+
+        let now = Instant::now();
+        clock.update(now - last_frame, &mut game);
+        last_frame = now;
+
+        // Only the latest message is shown.
+        let events: Vec<_> = game.drain_events().collect();
+        for event in events {
+            match event {
+                GameEvent::QuitRequested => break 'frames,
+                GameEvent::RestartRequested => {
+                    game = new_game();
+                    draw_held = false;
+                    message = String::from("Restarted");
+                }
+                GameEvent::SaveRequested | GameEvent::LoadRequested => {
+                    message = String::from("Saving and loading is not supported in the terminal");
+                }
+                _ => {
+                    if let Some(event_message) = render::event_message(&event) {
+                        message = event_message;
+                    }
+                }
+            }
+        }
+
+        let canvas = render::render(&game);
+        write!(
+            screen,
+            "{}{}{}{}\r\n{}{}\r\n{}",
+            cursor::Goto(1, 1),
+            canvas.to_ansi(),
+            clear::CurrentLine,
+            render::status_line(&game),
+            clear::CurrentLine,
+            message,
+            help
+        )?;
+        screen.flush()?;
+
+        thread::sleep(FRAME_DURATION);
+    }
+
+    write!(screen, "{}", cursor::Show)?;
+    screen.flush()
+}
@@ -0,0 +1,267 @@
+use std::fmt::Write;
+
+use termion::color;
+
+use spider_backend::model::enemy::{CollisionShape, EnemyKind};
+use spider_backend::model::event::GameEvent;
+use spider_backend::model::game::{DrawMode, Game, GameState};
+use spider_backend::model::point::Point;
+use spider_backend::model::rectilinear::Line;
+
+/// Every unit of the field is two columns wide, because terminal cells are about twice as high as
+/// they are wide.
+pub const COLUMNS_PER_UNIT: usize = 2;
+
+const CLAIMED: char = '░';
+const BORDER_HORIZONTAL: char = '─';
+const BORDER_VERTICAL: char = '│';
+const BORDER_VERTEX: char = '+';
+const TRAIL: char = '•';
+const ENEMY_PATH: char = '~';
+const SPIDER: char = '@';
+const SNAKE: char = 'S';
+const SPARX: char = '*';
+const ENEMY: char = 'E';
+const FUSE: char = '%';
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Style {
+    Plain,
+    Claimed,
+    Border,
+    Trail,
+    Enemy,
+    Spider,
+}
+
+impl Style {
+    fn escape(self) -> String {
+        match self {
+            Style::Plain => color::Fg(color::Reset).to_string(),
+            Style::Claimed => color::Fg(color::Blue).to_string(),
+            Style::Border => color::Fg(color::White).to_string(),
+            Style::Trail => color::Fg(color::Yellow).to_string(),
+            Style::Enemy => color::Fg(color::LightRed).to_string(),
+            Style::Spider => color::Fg(color::LightGreen).to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+/// The characters of a game rendered into a grid of terminal cells. The point `(x, y)` of the
+/// field is at row `y` and column `x * COLUMNS_PER_UNIT`; the columns in between show the
+/// horizontal lines that connect neighbouring points.
+#[derive(Clone, Debug)]
+pub struct Canvas {
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    pub fn new(width: i32, height: i32) -> Canvas {
+        let columns = width.max(0) as usize * COLUMNS_PER_UNIT + 1;
+        let rows = height.max(0) as usize + 1;
+        let blank = Cell {
+            ch: ' ',
+            style: Style::Plain,
+        };
+
+        Canvas {
+            cells: vec![vec![blank; columns]; rows],
+        }
+    }
+
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.cells
+    }
+
+    fn set(&mut self, row: i32, column: i32, ch: char, style: Style) {
+        if row < 0 || column < 0 {
+            return;
+        }
+        if let Some(cell) = self
+            .cells
+            .get_mut(row as usize)
+            .and_then(|cells| cells.get_mut(column as usize))
+        {
+            *cell = Cell { ch, style };
+        }
+    }
+
+    fn set_point(&mut self, point: &Point, ch: char, style: Style) {
+        self.set(point.y, point.x * COLUMNS_PER_UNIT as i32, ch, style);
+    }
+
+    // Draws every point of the line and the columns in between. The vertices are drawn with
+    // `vertex`, if given.
+    fn draw_line(&mut self, line: &Line<&Point>, ch: char, vertex: Option<char>, style: Style) {
+        let (start, end) = (line.start(), line.end());
+        let step = line.direction().to_point();
+        let (row, column) = (start.y, start.x * COLUMNS_PER_UNIT as i32);
+        let len = (end.x - start.x).abs() + (end.y - start.y).abs();
+        let column_step = step.x.signum();
+
+        for i in 0..=len {
+            let point_ch = match vertex {
+                Some(vertex) if i == 0 || i == len => vertex,
+                _ => ch,
+            };
+            let (row, column) = (
+                row + i * step.y,
+                column + i * step.x * COLUMNS_PER_UNIT as i32,
+            );
+            self.set(row, column, point_ch, style);
+
+            if i < len && column_step != 0 {
+                for offset in 1..COLUMNS_PER_UNIT as i32 {
+                    self.set(row, column + offset * column_step, ch, style);
+                }
+            }
+        }
+    }
+
+    /// Writes the canvas with ANSI colours, one line per row.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.rows() {
+            let mut style = None;
+            for cell in row {
+                if style != Some(cell.style) {
+                    out.push_str(&cell.style.escape());
+                    style = Some(cell.style);
+                }
+                out.push(cell.ch);
+            }
+            out.push_str(&Style::Plain.escape());
+            out.push_str("\r\n");
+        }
+        out
+    }
+}
+
+fn line_char(line: &Line<&Point>) -> char {
+    if line.horizontal() {
+        BORDER_HORIZONTAL
+    } else {
+        BORDER_VERTICAL
+    }
+}
+
+/// Renders the claimed area, the border of the free polygon, the spider's trail, the enemies, the
+/// fuse and the spider, in that order.
+pub fn render(game: &Game) -> Canvas {
+    let field = game.field();
+    let mut canvas = Canvas::new(field.width(), field.height());
+
+    for y in 0..=field.height() {
+        for x in 0..=field.width() {
+            let point = Point::new(x, y);
+            if !field.is_free(&point) {
+                canvas.set_point(&point, CLAIMED, Style::Claimed);
+            }
+
+            // The columns up to the next point belong to the step between the points.
+            if x < field.width() && !field.is_free_step(&point, &Point::new(x + 1, y)) {
+                for offset in 1..COLUMNS_PER_UNIT as i32 {
+                    canvas.set(
+                        y,
+                        x * COLUMNS_PER_UNIT as i32 + offset,
+                        CLAIMED,
+                        Style::Claimed,
+                    );
+                }
+            }
+        }
+    }
+
+    for line in field.free_polygon().line_iter() {
+        let ch = line_char(&line);
+        canvas.draw_line(&line, ch, Some(BORDER_VERTEX), Style::Border);
+    }
+
+    if let Some(path) = game.spider().path() {
+        for line in path.line_iter() {
+            canvas.draw_line(&line, TRAIL, None, Style::Trail);
+        }
+    }
+
+    for enemy in game.active_enemies() {
+        if let CollisionShape::Path(path) = enemy.collision_shape() {
+            for line in path.line_iter() {
+                canvas.draw_line(&line, ENEMY_PATH, None, Style::Enemy);
+            }
+        }
+
+        let ch = match enemy.kind() {
+            Some(EnemyKind::Snake) => SNAKE,
+            Some(EnemyKind::Sparx) => SPARX,
+            None => ENEMY,
+        };
+        canvas.set_point(enemy.pos(), ch, Style::Enemy);
+    }
+
+    if let Some(pos) = game.fuse().and_then(|fuse| fuse.pos(game.spider())) {
+        canvas.set_point(&pos, FUSE, Style::Enemy);
+    }
+
+    canvas.set_point(game.spider().pos(), SPIDER, Style::Spider);
+
+    canvas
+}
+
+/// The line below the field with the score, the lives and the state of the game.
+pub fn status_line(game: &Game) -> String {
+    let mut line = String::new();
+    write!(
+        line,
+        "Score: {}  Lives: {}  Claimed: {}%",
+        game.score().points(),
+        game.lives(),
+        game.field().claimed_percentage()
+    )
+    .unwrap();
+
+    if let Some(caption) = state_caption(game.state()) {
+        write!(line, "  {}", caption).unwrap();
+    }
+    line
+}
+
+/// A message about `event` for the line below the status line, if the event is worth showing.
+pub fn event_message(event: &GameEvent) -> Option<String> {
+    match event {
+        GameEvent::AreaClaimed { area, points, .. } => {
+            Some(format!("Claimed {} units for {} points", area, points))
+        }
+        GameEvent::PathAbandoned => Some(String::from("Path abandoned")),
+        GameEvent::FuseLit => Some(String::from("The fuse is lit, keep moving!")),
+        GameEvent::SpiderEaten => Some(String::from("Eaten!")),
+        GameEvent::LevelComplete { bonus } => Some(format!("Bonus: {} points", bonus)),
+        GameEvent::SpeedChanged { level } => Some(format!("Speed: {:+}", level)),
+        GameEvent::DrawModeChanged {
+            mode: DrawMode::Slow,
+        } => Some(String::from("Slow draw, double points")),
+        GameEvent::DrawModeChanged {
+            mode: DrawMode::Fast,
+        } => Some(String::from("Fast draw")),
+        _ => None,
+    }
+}
+
+fn state_caption(state: GameState) -> Option<&'static str> {
+    match state {
+        GameState::Ready => Some("Press an arrow key to start"),
+        GameState::Running => None,
+        GameState::Paused => Some("Paused"),
+        GameState::LifeLost => Some("Life lost, press an arrow key to continue"),
+        GameState::LevelComplete => Some("Level complete"),
+        GameState::GameOver => Some("Game over"),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+use super::*;
+
+use spider_backend::model::config::{EnemyConfig, GameConfig};
+use spider_backend::model::sparx::Orientation;
+use spider_backend::model::speed::SUBSTEPS_PER_STEP;
+use spider_backend::router::RouterCommand;
+use spider_backend::test_util;
+
+// Places the enemies where the rendered rows expect them.
+fn get_config() -> GameConfig {
+    GameConfig {
+        enemies: vec![
+            EnemyConfig::Snake {
+                pos: Point::new(8, 2),
+                aggression: 0.0,
+                speed: SUBSTEPS_PER_STEP,
+            },
+            EnemyConfig::Sparx {
+                pos: Point::new(0, 4),
+                orientation: Orientation::Clockwise,
+                speed: SUBSTEPS_PER_STEP,
+            },
+        ],
+        claim_threshold: 100,
+        seed: 0,
+        ..test_util::get_config()
+    }
+}
+
+fn row(canvas: &Canvas, row: usize) -> String {
+    canvas.rows()[row].iter().map(|cell| cell.ch).collect()
+}
+
+fn point(canvas: &Canvas, point: Point) -> Cell {
+    canvas.rows()[point.y as usize][point.x as usize * COLUMNS_PER_UNIT]
+}
+
+#[test]
+fn render_field() {
+    let canvas = render(&get_config().build());
+
+    assert_eq!("+─────────@─────────+", row(&canvas, 0));
+    assert_eq!("│                   │", row(&canvas, 1));
+    assert_eq!("│               S   │", row(&canvas, 2));
+    assert_eq!("*───────────────────+", row(&canvas, 4));
+}
+
+#[test]
+fn render_trail_and_claimed_area() {
+    let mut game = GameConfig {
+        spider_pos: Point::new(7, 0),
+        enemies: Vec::new(),
+        ..get_config()
+    }
+    .build();
+    game.handle_command(RouterCommand::DRAW);
+    game.handle_command(RouterCommand::DOWN);
+    game.update_state();
+    game.update_state();
+
+    let canvas = render(&game);
+    assert_eq!('•', point(&canvas, Point::new(7, 1)).ch);
+    assert_eq!('@', point(&canvas, Point::new(7, 2)).ch);
+
+    game.update_state();
+    game.update_state();
+
+    // The smaller part on the right has been claimed.
+    let canvas = render(&game);
+    assert_eq!(
+        Cell {
+            ch: CLAIMED,
+            style: Style::Claimed
+        },
+        point(&canvas, Point::new(9, 2))
+    );
+    assert_eq!(Style::Border, point(&canvas, Point::new(7, 2)).style);
+    assert_eq!("│             │░░░░░░", row(&canvas, 2));
+}
+
+#[test]
+fn render_status_line() {
+    let game = get_config().build();
+
+    assert_eq!(
+        "Score: 0  Lives: 3  Claimed: 0%  Press an arrow key to start",
+        status_line(&game)
+    );
+}
+
+#[test]
+fn render_event_message() {
+    assert_eq!(
+        Some(String::from("Path abandoned")),
+        event_message(&GameEvent::PathAbandoned)
+    );
+    assert_eq!(
+        Some(String::from("Speed: -1")),
+        event_message(&GameEvent::SpeedChanged { level: -1 })
+    );
+    assert_eq!(None, event_message(&GameEvent::Paused));
+}
+
+#[test]
+fn render_fuse() {
+    let mut game = GameConfig {
+        spider_pos: Point::new(7, 0),
+        enemies: Vec::new(),
+        fuse_delay: Some(0),
+        ..get_config()
+    }
+    .build();
+    game.handle_command(RouterCommand::DRAW);
+    game.handle_command(RouterCommand::DOWN);
+    game.update_state();
+    game.update_state();
+    game.handle_command(RouterCommand::STOP);
+    game.update_state();
+
+    let canvas = render(&game);
+    assert_eq!(
+        Cell {
+            ch: FUSE,
+            style: Style::Enemy
+        },
+        point(&canvas, Point::new(7, 1))
+    );
+}
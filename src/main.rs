@@ -1,5 +1,5 @@
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 use gio::prelude::*;
 use glib::clone;
@@ -8,14 +8,19 @@ use gtk::prelude::*;
 use spider_backend;
 use spider_gtk::gui::{self, SpiderGui};
 
-use spider_backend::model::point::{Direction, Point};
-use spider_backend::model::field::Field;
-use spider_backend::model::snake::Snake;
-use spider_backend::model::spider::Spider;
-use spider_backend::model::game::Game;
+use spider_backend::bindings::{self, Bindings};
+use spider_backend::clock::GameClock;
+use spider_backend::model::config::GameConfig;
+use spider_backend::model::event::GameEvent;
+use spider_backend::replay::{Replay, ReplayPlayer};
+use spider_backend::router::{Router, RouterCommand};
 
 use spider_gtk::gui::draw::Drawable;
-use spider_gtk::gui::router::{Router, RouterCommand};
+
+/// Path of a replay file the game is recorded to when the window is closed.
+const RECORD_REPLAY_VAR: &str = "SPIDER_RECORD_REPLAY";
+/// Path of a replay file that is played back instead of taking input.
+const PLAY_REPLAY_VAR: &str = "SPIDER_PLAY_REPLAY";
 
 fn default_router() -> Router<gdk::enums::key::Key> {
     let mut router = Router::new();
@@ -27,35 +32,196 @@ fn default_router() -> Router<gdk::enums::key::Key> {
     router.bind(key::Right, RouterCommand::RIGHT);
     router.bind(key::space, RouterCommand::STOP);
     router.bind(key::p, RouterCommand::PAUSE);
+    router.bind(key::Shift_L, RouterCommand::DRAW);
+    router.bind(key::Shift_R, RouterCommand::DRAW);
+    router.bind(key::f, RouterCommand::TOGGLE_DRAW_MODE);
+    // Not `plus`, which needs Shift on most layouts and Shift is the draw key.
+    router.bind(key::equal, RouterCommand::SPEED_UP);
+    router.bind(key::KP_Add, RouterCommand::SPEED_UP);
+    router.bind(key::minus, RouterCommand::SLOW_DOWN);
+    router.bind(key::KP_Subtract, RouterCommand::SLOW_DOWN);
+    router.bind(key::r, RouterCommand::RESTART);
+    router.bind(key::q, RouterCommand::QUIT);
+    router.bind(key::s, RouterCommand::SAVE);
+    router.bind(key::o, RouterCommand::LOAD);
 
     router
 }
 
+fn key_from_name(name: &str) -> Option<gdk::enums::key::Key> {
+    match gdk::keyval_from_name(name) {
+        gdk::enums::key::VoidSymbol => None,
+        key => Some(key),
+    }
+}
+
+fn key_name(key: &gdk::enums::key::Key) -> Option<String> {
+    gdk::keyval_name(*key).map(|name| name.to_string())
+}
+
+// Applies the user's bindings file to `router`. Errors are reported and the default bindings kept.
+fn load_bindings(router: &mut Router<gdk::enums::key::Key>) {
+    let path = match bindings::user_bindings_path() {
+        Some(path) => path,
+        None => return,
+    };
+
+    let result = match Bindings::load(&path) {
+        Ok(Some(bindings)) => bindings.apply(router, key_from_name).map_err(|err| err.to_string()),
+        Ok(None) => Ok(()),
+        Err(err) => Err(err.to_string()),
+    };
+    if let Err(message) = result {
+        eprintln!("Could not load key bindings {:?}: {}", path, message);
+    }
+}
+
+fn load_replay(path: &std::ffi::OsStr) -> Replay {
+    let text = std::fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("Could not read replay {:?}: {}", path, err);
+        std::process::exit(1);
+    });
+    Replay::parse(&text).unwrap_or_else(|err| {
+        eprintln!("Could not parse replay {:?}: {}", path, err);
+        std::process::exit(1);
+    })
+}
+
+// The configuration of a new game, which is the replay's if there is one and a random game
+// otherwise.
+fn new_config(replay: Option<&Replay>) -> GameConfig {
+    match replay {
+        Some(replay) => replay.config().clone(),
+        None => GameConfig {
+            seed: rand::random(),
+            ..GameConfig::default()
+        },
+    }
+}
+
 fn main() {
-    let field = Field::new(50, 20);
-    let spider = Spider::new(Point::new(0, 0), Direction::RIGHT, Point::new(0, 0), Point::new(50, 20));
-    let snake = Snake::new(Point::new(10, 10));
+    let replay = std::env::var_os(PLAY_REPLAY_VAR).map(|path| load_replay(&path));
+    // The configuration the current game was built from.
+    let config_rc = Rc::new(RefCell::new(new_config(replay.as_ref())));
+    // A loaded game wasn't built from the configuration, so it can't be recorded.
+    let recordable_rc = Rc::new(Cell::new(true));
 
-    let game = Game::new(field, spider, snake);
-    let game_rc = Rc::new(RefCell::new(game));
+    let game_rc = Rc::new(RefCell::new(config_rc.borrow().build()));
+    let player_rc = Rc::new(RefCell::new(replay.clone().map(ReplayPlayer::new)));
 
-    gtk::init().unwrap();
-    gtk::timeout_add(500, clone!(@strong game_rc => move || {
-        game_rc.borrow_mut().update_state();
-        Continue(true)
+    let clock_rc = Rc::new(RefCell::new(GameClock::new()));
+    let update_game: gui::FrameCallback = Rc::new(clone!(@strong game_rc, @strong player_rc, @strong clock_rc => move |elapsed| {
+        let mut game = game_rc.borrow_mut();
+        let mut clock = clock_rc.borrow_mut();
+        clock.set_speed_level(game.speed_level());
+        for _ in 0..clock.advance(elapsed) {
+            match player_rc.borrow_mut().as_mut() {
+                Some(player) => {
+                    player.step(&mut game);
+                }
+                None => game.update_state(),
+            }
+        }
+        // The window shows the state of the game, so only the session requests are of interest.
+        let requests: Vec<_> = game
+            .drain_events()
+            .filter_map(|event| match event {
+                GameEvent::RestartRequested => Some(gui::SessionRequest::Restart),
+                GameEvent::QuitRequested => Some(gui::SessionRequest::Quit),
+                GameEvent::SaveRequested => Some(gui::SessionRequest::Save),
+                GameEvent::LoadRequested => Some(gui::SessionRequest::Load),
+                _ => None,
+            })
+            .collect();
+        requests
     }));
 
+    gtk::init().unwrap();
+
     let draw_game: gui::DrawCallback = Rc::new(clone!(@strong game_rc => move |drawing_area, cx| {
         game_rc.borrow().draw_restore(drawing_area, cx);
         Inhibit(false)
     }));
 
-    let router = default_router();
-    let key_press_handler: gui::KeyEventCallback = Rc::new(clone!(@strong game_rc => move |key_ev| {
-        router.route(key_ev.get_keyval(), &mut game_rc.borrow_mut());
+    let mut router = default_router();
+    load_bindings(&mut router);
+    let router_rc = Rc::new(RefCell::new(router));
+    let key_press_handler: gui::KeyEventCallback = Rc::new(clone!(@strong game_rc, @strong player_rc, @strong router_rc => move |key_ev| {
+        let mut router = router_rc.borrow_mut();
+        let keyval = key_ev.get_keyval();
+        // Game input would make a replay diverge from the recording, session commands don't.
+        let is_session = router.command(&keyval).map_or(false, |command| command.is_session());
+        if player_rc.borrow().is_none() || is_session {
+            router.route(keyval, &mut game_rc.borrow_mut());
+        }
+        Inhibit(false)
+    }));
+    let key_release_handler: gui::KeyEventCallback = Rc::new(clone!(@strong game_rc, @strong player_rc, @strong router_rc => move |key_ev| {
+        if player_rc.borrow().is_none() {
+            router_rc.borrow_mut().route_release(key_ev.get_keyval(), &mut game_rc.borrow_mut());
+        }
         Inhibit(false)
     }));
 
-    let spider_gui = SpiderGui::new(draw_game, key_press_handler);
+    let save_game: gui::FileCallback = Rc::new(clone!(@strong game_rc => move |path| {
+        let json = serde_json::to_string(&*game_rc.borrow()).map_err(|err| err.to_string())?;
+        std::fs::write(path, json).map_err(|err| err.to_string())
+    }));
+
+    let load_game: gui::FileCallback = Rc::new(clone!(@strong game_rc, @strong player_rc, @strong recordable_rc => move |path| {
+        let json = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let game = serde_json::from_str(&json).map_err(|err| err.to_string())?;
+        *game_rc.borrow_mut() = game;
+        // A loaded game is no longer the one being replayed.
+        *player_rc.borrow_mut() = None;
+        recordable_rc.set(false);
+        Ok(())
+    }));
+
+    let restart_game: gui::RestartCallback = Rc::new(clone!(@strong config_rc, @strong recordable_rc, @strong replay, @strong game_rc, @strong player_rc => move || {
+        // A replay starts over along with the game, any other game starts over with a new seed.
+        let config = new_config(replay.as_ref());
+        *game_rc.borrow_mut() = config.build();
+        *config_rc.borrow_mut() = config;
+        *player_rc.borrow_mut() = replay.clone().map(ReplayPlayer::new);
+        recordable_rc.set(true);
+    }));
+
+    let get_bindings: gui::BindingsGetter = Rc::new(clone!(@strong router_rc => move || {
+        Bindings::from_router(&router_rc.borrow(), key_name)
+    }));
+
+    let set_bindings: gui::BindingsCallback = Rc::new(clone!(@strong router_rc => move |bindings| {
+        // The bindings list every command, so they replace all of the defaults.
+        let mut router = default_router();
+        bindings.apply(&mut router, key_from_name).map_err(|err| err.to_string())?;
+        let path = bindings::user_bindings_path()
+            .ok_or_else(|| String::from("Could not find the config directory"))?;
+        bindings.save(&path).map_err(|err| format!("Could not write {:?}: {}", path, err))?;
+        *router_rc.borrow_mut() = router;
+        Ok(())
+    }));
+
+    let mut spider_gui = SpiderGui::new(
+        draw_game,
+        key_press_handler,
+        key_release_handler,
+        save_game,
+        load_game,
+        update_game,
+    );
+    spider_gui.set_bindings_callbacks(get_bindings, set_bindings);
+    spider_gui.set_restart_callback(restart_game);
     spider_gui.run();
+
+    if let Some(path) = std::env::var_os(RECORD_REPLAY_VAR) {
+        if recordable_rc.get() {
+            let replay = Replay::record(&config_rc.borrow(), &game_rc.borrow());
+            if let Err(err) = std::fs::write(&path, replay.to_string()) {
+                eprintln!("Could not write replay {:?}: {}", path, err);
+            }
+        } else {
+            eprintln!("Not writing replay {:?} of a loaded game", path);
+        }
+    }
 }